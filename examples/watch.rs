@@ -0,0 +1,15 @@
+use std::sync::mpsc;
+
+use iana_time_zone::{get_timezone, watch, GetTimezoneError};
+
+fn main() -> Result<(), GetTimezoneError> {
+    println!("{}", get_timezone()?);
+    let (sender, receiver) = mpsc::channel();
+    let _watcher = watch(move |change| {
+        let _ = sender.send(change);
+    })?;
+    for change in receiver {
+        println!("{}", change.new);
+    }
+    Ok(())
+}
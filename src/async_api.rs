@@ -0,0 +1,272 @@
+//! An asynchronous API for the tokio runtime.
+
+use std::panic;
+
+use crate::GetTimezoneError;
+
+/// Get the current IANA time zone without blocking the async runtime.
+///
+/// [`get_timezone()`](crate::get_timezone) reads files, or calls APIs of the operating system.
+/// This function runs it on the blocking thread pool of tokio instead, so it must be called in
+/// the context of a tokio runtime.
+///
+/// The feature `tokio` must be enabled.
+///
+/// ```rust
+/// # tokio::runtime::Builder::new_current_thread().build().unwrap().block_on(async {
+/// let tz_str = iana_time_zone::get_timezone_async().await?;
+/// println!("The current time zone is: {}", tz_str);
+/// # Ok::<(), iana_time_zone::GetTimezoneError>(())
+/// # }).unwrap();
+/// ```
+pub async fn get_timezone_async() -> Result<String, GetTimezoneError> {
+    match tokio::task::spawn_blocking(crate::get_timezone).await {
+        Ok(result) => result,
+        Err(err) => match err.try_into_panic() {
+            Ok(payload) => panic::resume_unwind(payload),
+            // The runtime is shutting down.
+            Err(_) => Err(GetTimezoneError::os("tokio::task::spawn_blocking()", None)),
+        },
+    }
+}
+
+#[cfg(all(target_os = "linux", not(target_env = "ohos")))]
+pub use self::changes::{timezone_changes, timezone_changes_in_root, TimezoneChanges};
+
+#[cfg(all(target_os = "linux", not(target_env = "ohos")))]
+mod changes {
+    use std::future::Future;
+    use std::panic;
+    use std::path::{Path, PathBuf};
+    use std::pin::Pin;
+    use std::ptr;
+    use std::task::{Context, Poll};
+
+    use futures_core::Stream;
+    use tokio::io::unix::AsyncFd;
+    use tokio::runtime::Handle;
+    use tokio::task::JoinHandle;
+
+    use crate::inotify::{Fd, Inotify, DEBOUNCE};
+    use crate::{Detection, GetTimezoneError, OsCode, TimezoneChange};
+
+    /// A [`Stream`] of changes of the time zone, see [`timezone_changes()`].
+    #[derive(Debug)]
+    pub struct TimezoneChanges {
+        inotify: AsyncFd<Inotify>,
+        /// A timerfd that expires when no more events arrived for [`DEBOUNCE`].
+        timer: AsyncFd<Fd>,
+        armed: bool,
+        runtime: Handle,
+        /// The detection that runs on the blocking thread pool.
+        detecting: Option<JoinHandle<Option<String>>>,
+    }
+
+    /// Get a [`Stream`] of the changes of the time zone of the system.
+    ///
+    /// This is the asynchronous counterpart of [`watch()`](crate::watch), with the same
+    /// semantics, but no background thread: the inotify events are read by the runtime, and the
+    /// time zone is detected again on its blocking thread pool, like in
+    /// [`get_timezone_async()`](crate::get_timezone_async). The stream does not end until it is
+    /// dropped, which stops watching, or until the runtime shuts down.
+    ///
+    /// The current time zone is detected before this function returns, so the function itself
+    /// blocks like [`get_timezone()`](crate::get_timezone) does.
+    ///
+    /// It must be called in the context of a tokio runtime that has IO enabled. The feature
+    /// `tokio` must be enabled, and only Linux is supported.
+    ///
+    /// ```rust,no_run
+    /// use futures_util::StreamExt;
+    ///
+    /// # tokio::runtime::Builder::new_current_thread().enable_io().build().unwrap().block_on(async {
+    /// let mut changes = iana_time_zone::timezone_changes()?;
+    /// while let Some(change) = changes.next().await {
+    ///     println!("The time zone changed from {:?} to {}", change.old, change.new);
+    /// }
+    /// # Ok::<(), iana_time_zone::GetTimezoneError>(())
+    /// # }).unwrap();
+    /// ```
+    pub fn timezone_changes() -> Result<TimezoneChanges, GetTimezoneError> {
+        TimezoneChanges::new(PathBuf::from("/"), |_| crate::detect())
+    }
+
+    /// Get a [`Stream`] of the changes of the time zone configured in an alternate root file
+    /// system.
+    ///
+    /// See [`timezone_changes()`] and [`watch_in_root()`](crate::watch_in_root).
+    pub fn timezone_changes_in_root(root: &Path) -> Result<TimezoneChanges, GetTimezoneError> {
+        TimezoneChanges::new(root.to_owned(), crate::detect_in_root)
+    }
+
+    impl TimezoneChanges {
+        fn new(
+            root: PathBuf,
+            detect: fn(&Path) -> Result<Detection, GetTimezoneError>,
+        ) -> Result<Self, GetTimezoneError> {
+            let inotify = Inotify::new(root, detect)?;
+            // SAFETY: The function has no preconditions, and the result is checked.
+            let timer = Fd::new(
+                unsafe {
+                    libc::timerfd_create(
+                        libc::CLOCK_MONOTONIC,
+                        libc::TFD_CLOEXEC | libc::TFD_NONBLOCK,
+                    )
+                },
+                "timerfd_create()",
+            )?;
+            let async_fd = |err: std::io::Error| {
+                GetTimezoneError::os("AsyncFd::new()", err.raw_os_error().map(OsCode::Errno))
+            };
+            // `AsyncFd::register()` needs a newer tokio than we require. Both file descriptors
+            // are owned, and stay open until the `AsyncFd` is dropped, as it requires.
+            #[allow(deprecated)]
+            let inotify = AsyncFd::new(inotify).map_err(async_fd)?;
+            #[allow(deprecated)]
+            let timer = AsyncFd::new(timer).map_err(async_fd)?;
+            Ok(Self {
+                inotify,
+                timer,
+                armed: false,
+                runtime: Handle::current(),
+                detecting: None,
+            })
+        }
+
+        /// Let the timer expire after [`DEBOUNCE`], or restart it if it is already running.
+        fn arm(&mut self) {
+            let value = libc::itimerspec {
+                it_interval: libc::timespec {
+                    tv_sec: 0,
+                    tv_nsec: 0,
+                },
+                it_value: libc::timespec {
+                    tv_sec: 0,
+                    tv_nsec: DEBOUNCE.as_nanos() as _,
+                },
+            };
+            // SAFETY: The file descriptor is valid, and the old value is not requested.
+            let ret = unsafe {
+                libc::timerfd_settime(self.timer.get_ref().0, 0, &value, ptr::null_mut())
+            };
+            self.armed = ret == 0;
+        }
+
+        /// Consume the expiration of the timer, and return if it expired.
+        fn expired(&self) -> bool {
+            let mut buf = [0_u8; 8];
+            // SAFETY: The buffer is valid for its length. A timerfd is read in units of 8 bytes.
+            let len =
+                unsafe { libc::read(self.timer.get_ref().0, buf.as_mut_ptr().cast(), buf.len()) };
+            len == buf.len() as isize
+        }
+    }
+
+    impl Stream for TimezoneChanges {
+        type Item = TimezoneChange;
+
+        fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+            let this = self.get_mut();
+            loop {
+                // Every burst of events restarts the timer, so only the last one is detected.
+                match this.inotify.poll_read_ready_mut(cx) {
+                    Poll::Ready(Ok(mut guard)) => {
+                        let relevant = guard.get_inner_mut().read_events();
+                        guard.clear_ready();
+                        if relevant {
+                            this.arm();
+                        }
+                        continue;
+                    }
+                    Poll::Ready(Err(_)) => return Poll::Ready(None),
+                    Poll::Pending => {}
+                }
+                if let Some(detecting) = &mut this.detecting {
+                    let new = match Pin::new(detecting).poll(cx) {
+                        Poll::Ready(Ok(new)) => new,
+                        Poll::Ready(Err(err)) => match err.try_into_panic() {
+                            Ok(payload) => panic::resume_unwind(payload),
+                            // The runtime is shutting down.
+                            Err(_) => return Poll::Ready(None),
+                        },
+                        Poll::Pending => return Poll::Pending,
+                    };
+                    this.detecting = None;
+                    if let Some(change) = this.inotify.get_mut().update(new) {
+                        return Poll::Ready(Some(change));
+                    }
+                    continue;
+                }
+                if !this.armed {
+                    return Poll::Pending;
+                }
+                match this.timer.poll_read_ready(cx) {
+                    Poll::Ready(Ok(mut guard)) => {
+                        let expired = this.expired();
+                        guard.clear_ready();
+                        if expired {
+                            this.armed = false;
+                            // Detecting the time zone reads files, and may index the whole
+                            // zoneinfo database, so it must not block the runtime.
+                            let detect = this.inotify.get_mut().detector();
+                            this.detecting = Some(this.runtime.spawn_blocking(detect));
+                        }
+                    }
+                    Poll::Ready(Err(_)) => return Poll::Ready(None),
+                    Poll::Pending => return Poll::Pending,
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::get_timezone_async;
+
+    #[test]
+    fn test_get_timezone_async() {
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .build()
+            .unwrap();
+        let tz_str = runtime.block_on(get_timezone_async()).unwrap();
+        assert_eq!(tz_str, crate::get_timezone().unwrap());
+    }
+
+    #[test]
+    #[cfg(all(target_os = "linux", not(target_env = "ohos")))]
+    fn test_timezone_changes_in_root() {
+        use std::fs;
+
+        use futures_util::StreamExt;
+
+        use super::timezone_changes_in_root;
+
+        let root =
+            std::env::temp_dir().join(format!("iana-time-zone-stream-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+        fs::create_dir_all(root.join("etc")).unwrap();
+        fs::write(root.join("etc/timezone"), "Europe/Berlin\n").unwrap();
+
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .enable_io()
+            .build()
+            .unwrap();
+        runtime.block_on(async {
+            let mut changes = timezone_changes_in_root(&root).unwrap();
+            fs::write(root.join("etc/timezone"), "America/New_York\n").unwrap();
+            let change = changes.next().await.unwrap();
+            assert_eq!(change.old.as_deref(), Some("Europe/Berlin"));
+            assert_eq!(change.new, "America/New_York");
+
+            // Writing the same time zone again is not a change.
+            fs::write(root.join("etc/timezone"), "America/New_York\n").unwrap();
+            fs::write(root.join("etc/timezone"), "Asia/Tokyo\n").unwrap();
+            let change = changes.next().await.unwrap();
+            assert_eq!(change.old.as_deref(), Some("America/New_York"));
+            assert_eq!(change.new, "Asia/Tokyo");
+        });
+
+        fs::remove_dir_all(&root).unwrap();
+    }
+}
@@ -0,0 +1,347 @@
+//! Print the IANA time zone that `iana-time-zone` detects, and how it was found.
+//!
+//! Run `iana-time-zone --help` for the usage. The feature `cli` must be enabled.
+
+use std::env;
+use std::fmt::Write as _;
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+use iana_time_zone::{BuiltinSource, Detection, Detector, GetTimezoneError};
+
+const USAGE: &str = "\
+Usage: iana-time-zone [OPTIONS]
+
+Print the IANA time zone of this system.
+
+Options:
+      --json         Print the full detection report as JSON
+      --all          Show the result of every source, not only the first match
+      --root <PATH>  Inspect the file system at PATH instead of the running system
+      --diagnose     Explain what every source found, to paste into a bug report. Only
+                     --root can be combined with it
+  -h, --help         Print this help
+  -V, --version      Print the version
+
+Exit status:
+  0  The time zone was found
+  1  Detecting the time zone failed
+  2  Invalid arguments
+  3  No source has a time zone configured";
+
+/// The exit status if detecting the time zone failed.
+const EXIT_ERROR: u8 = 1;
+/// The exit status of invalid arguments.
+const EXIT_USAGE: u8 = 2;
+/// The exit status if the system has no time zone configured.
+const EXIT_NOT_CONFIGURED: u8 = 3;
+
+#[derive(Debug, Default, PartialEq, Eq)]
+struct Args {
+    json: bool,
+    all: bool,
+    diagnose: bool,
+    root: Option<PathBuf>,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+enum Command {
+    Run(Args),
+    Help,
+    Version,
+}
+
+fn parse_args(args: impl IntoIterator<Item = String>) -> Result<Command, String> {
+    let mut parsed = Args::default();
+    let mut args = args.into_iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--json" => parsed.json = true,
+            "--all" => parsed.all = true,
+            "--root" => match args.next() {
+                Some(root) => parsed.root = Some(root.into()),
+                None => return Err("--root requires a path".to_owned()),
+            },
+            "--diagnose" => parsed.diagnose = true,
+            "-h" | "--help" => return Ok(Command::Help),
+            "-V" | "--version" => return Ok(Command::Version),
+            _ => match arg.strip_prefix("--root=") {
+                Some(root) => parsed.root = Some(root.into()),
+                None => return Err(format!("unexpected argument {:?}", arg)),
+            },
+        }
+    }
+    if parsed.diagnose && (parsed.json || parsed.all) {
+        return Err("--diagnose cannot be combined with --json or --all".to_owned());
+    }
+    Ok(Command::Run(parsed))
+}
+
+fn main() -> ExitCode {
+    let args = match parse_args(env::args().skip(1)) {
+        Ok(Command::Run(args)) => args,
+        Ok(Command::Help) => {
+            println!("{}", USAGE);
+            return ExitCode::SUCCESS;
+        }
+        Ok(Command::Version) => {
+            println!("iana-time-zone {}", env!("CARGO_PKG_VERSION"));
+            return ExitCode::SUCCESS;
+        }
+        Err(err) => {
+            eprintln!("error: {}\n\n{}", err, USAGE);
+            return ExitCode::from(EXIT_USAGE);
+        }
+    };
+
+    let (detector, sources) = match &args.root {
+        None => (Detector::new(), BuiltinSource::defaults()),
+        #[cfg(unix)]
+        Some(root) => (Detector::in_root(root), BuiltinSource::in_root()),
+        #[cfg(not(unix))]
+        Some(_) => {
+            eprintln!("error: --root is only supported on Unix");
+            return ExitCode::from(EXIT_USAGE);
+        }
+    };
+
+    if args.diagnose {
+        #[cfg(unix)]
+        let diagnostics = match &args.root {
+            Some(root) => iana_time_zone::diagnose_in_root(root),
+            None => iana_time_zone::diagnose(),
+        };
+        // `--root` was rejected above.
+        #[cfg(not(unix))]
+        let diagnostics = iana_time_zone::diagnose();
+        print!("{}", diagnostics);
+        return exit_code(&diagnostics.result);
+    }
+
+    let result = detector.detect();
+
+    // Every source is run on its own, so it reports its result even if an earlier one matched.
+    let all = if args.all {
+        sources
+            .iter()
+            .map(|&source| {
+                let detector = Detector::empty().builtin(source);
+                #[cfg(unix)]
+                let detector = match &args.root {
+                    Some(root) => detector.root(root),
+                    None => detector,
+                };
+                (source, detector.detect())
+            })
+            .collect()
+    } else {
+        Vec::new()
+    };
+
+    if args.json {
+        println!("{}", json_report(&result, args.all.then_some(&all[..])));
+    } else {
+        for (source, result) in &all {
+            match result {
+                Ok(detection) => println!("{:<24}{}", source.name(), detection.name),
+                Err(err) => println!("{:<24}{} ({})", source.name(), status(err), err),
+            }
+        }
+        if args.all {
+            println!();
+        }
+        match &result {
+            Ok(detection) => println!("{}", detection.name),
+            Err(err) => eprintln!("error: {}", err),
+        }
+    }
+
+    exit_code(&result)
+}
+
+fn exit_code(result: &Result<Detection, GetTimezoneError>) -> ExitCode {
+    match result {
+        Ok(_) => ExitCode::SUCCESS,
+        Err(err) if err.is_not_configured() => ExitCode::from(EXIT_NOT_CONFIGURED),
+        Err(_) => ExitCode::from(EXIT_ERROR),
+    }
+}
+
+fn status(err: &GetTimezoneError) -> &'static str {
+    if err.is_not_configured() {
+        "not configured"
+    } else {
+        "error"
+    }
+}
+
+/// The report of `--json`, with the results of the single sources if `--all` was given.
+fn json_report(
+    result: &Result<Detection, GetTimezoneError>,
+    all: Option<&[(BuiltinSource, Result<Detection, GetTimezoneError>)]>,
+) -> String {
+    let mut json = json_result(result);
+    if let Some(all) = all {
+        json.pop();
+        json.push_str(",\"sources\":[");
+        for (idx, (source, result)) in all.iter().enumerate() {
+            if idx > 0 {
+                json.push(',');
+            }
+            let _ = write!(
+                json,
+                "{{\"source\":{},\"result\":{}}}",
+                json_string(source.name()),
+                json_result(result),
+            );
+        }
+        json.push_str("]}");
+    }
+    json
+}
+
+fn json_result(result: &Result<Detection, GetTimezoneError>) -> String {
+    let detection = match result {
+        Ok(detection) => detection,
+        Err(err) => return json_error(err),
+    };
+    let mut json = String::new();
+    let _ = write!(
+        json,
+        "{{\"status\":\"ok\",\"name\":{},\"source\":{},\"path\":{},\"leap_seconds\":{},\"posix_tz\":{},\"errors\":[",
+        json_string(&detection.name),
+        json_string(&detection.source.to_string()),
+        json_option(detection.path.as_ref().map(|path| path.display().to_string())),
+        detection.leap_seconds,
+        json_option(detection.posix_tz.as_deref()),
+    );
+    for (idx, (source, err)) in detection.errors.iter().enumerate() {
+        if idx > 0 {
+            json.push(',');
+        }
+        let _ = write!(
+            json,
+            "{{\"source\":{},\"error\":{}}}",
+            json_string(&source.to_string()),
+            json_string(&err.to_string()),
+        );
+    }
+    json.push_str("]}");
+    json
+}
+
+fn json_error(err: &GetTimezoneError) -> String {
+    let status = if err.is_not_configured() {
+        "not_configured"
+    } else {
+        "error"
+    };
+    format!(
+        "{{\"status\":\"{}\",\"error\":{}}}",
+        status,
+        json_string(&err.to_string()),
+    )
+}
+
+fn json_option(value: Option<impl AsRef<str>>) -> String {
+    match value {
+        Some(value) => json_string(value.as_ref()),
+        None => "null".to_owned(),
+    }
+}
+
+fn json_string(s: &str) -> String {
+    let mut json = String::with_capacity(s.len() + 2);
+    json.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => json.push_str("\\\""),
+            '\\' => json.push_str("\\\\"),
+            '\n' => json.push_str("\\n"),
+            '\r' => json.push_str("\\r"),
+            '\t' => json.push_str("\\t"),
+            c if c < ' ' => {
+                let _ = write!(json, "\\u{:04x}", c as u32);
+            }
+            c => json.push(c),
+        }
+    }
+    json.push('"');
+    json
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use iana_time_zone::{Context, Detection, GetTimezoneError, Source};
+
+    use super::{json_report, json_string, parse_args, Args, Command};
+
+    fn parse(args: &[&str]) -> Result<Command, String> {
+        parse_args(args.iter().map(|&arg| arg.to_owned()))
+    }
+
+    #[test]
+    fn test_parse_args() {
+        assert_eq!(parse(&[]), Ok(Command::Run(Args::default())));
+        assert_eq!(
+            parse(&["--json", "--all", "--root", "/mnt/image"]),
+            Ok(Command::Run(Args {
+                json: true,
+                all: true,
+                diagnose: false,
+                root: Some(PathBuf::from("/mnt/image")),
+            })),
+        );
+        assert_eq!(
+            parse(&["--root=/mnt/image"]),
+            Ok(Command::Run(Args {
+                root: Some(PathBuf::from("/mnt/image")),
+                ..Args::default()
+            })),
+        );
+        assert_eq!(parse(&["--json", "-h"]), Ok(Command::Help));
+        assert_eq!(parse(&["-V"]), Ok(Command::Version));
+        assert_eq!(
+            parse(&["--diagnose", "--root", "/mnt/image"]),
+            Ok(Command::Run(Args {
+                diagnose: true,
+                root: Some(PathBuf::from("/mnt/image")),
+                ..Args::default()
+            })),
+        );
+        parse(&["--diagnose", "--bogus"]).unwrap_err();
+        parse(&["--diagnose", "--json"]).unwrap_err();
+        parse(&["--all", "--diagnose"]).unwrap_err();
+        parse(&["--root"]).unwrap_err();
+        parse(&["Europe/Berlin"]).unwrap_err();
+    }
+
+    #[test]
+    fn test_json() {
+        assert_eq!(json_string("a \"b\"\\\n\u{1}"), r#""a \"b\"\\\n\u0001""#);
+
+        let mut detection = Detection::new("Europe/Berlin".to_owned(), Source::File);
+        detection.errors.push((
+            Source::EnvVar,
+            GetTimezoneError::NotConfigured {
+                context: Context::EnvVar("TZ"),
+            },
+        ));
+        assert_eq!(
+            json_report(&Ok(detection), None),
+            concat!(
+                r#"{"status":"ok","name":"Europe/Berlin","source":"file","path":null,"#,
+                r#""leap_seconds":false,"posix_tz":null,"errors":[{"source":"environment variable","#,
+                r#""error":"no time zone is configured in the environment variable TZ"}]}"#,
+            ),
+        );
+
+        let result = Err(GetTimezoneError::InvalidTimezone("Mars".to_owned()));
+        assert_eq!(
+            json_report(&result, Some(&[])),
+            r#"{"status":"error","error":"not an IANA time zone: \"Mars\"","sources":[]}"#,
+        );
+    }
+}
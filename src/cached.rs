@@ -0,0 +1,264 @@
+//! A cache of the detected time zone that is invalidated when the configuration changes.
+
+use std::env;
+use std::ffi::OsString;
+use std::fs;
+use std::path::Path;
+use std::sync::{Arc, Mutex, PoisonError};
+use std::time::{Duration, Instant, SystemTime};
+
+use crate::lazy::Lazy;
+use crate::GetTimezoneError;
+
+/// The files that the backend of this platform reads the time zone from.
+///
+/// `None` if the time zone is read from an API whose changes cannot be observed cheaply.
+#[cfg(any(all(target_os = "linux", not(target_env = "ohos")), target_os = "hurd"))]
+pub(crate) const WATCHED: Option<&[&str]> =
+    Some(&["/etc/localtime", "/etc/timezone", "/etc/config/system"]);
+#[cfg(any(target_os = "freebsd", target_os = "dragonfly"))]
+pub(crate) const WATCHED: Option<&[&str]> = Some(&["/var/db/zoneinfo", "/etc/localtime"]);
+#[cfg(any(target_os = "netbsd", target_os = "openbsd", target_vendor = "apple"))]
+pub(crate) const WATCHED: Option<&[&str]> = Some(&["/etc/localtime"]);
+#[cfg(any(target_os = "illumos", target_os = "solaris"))]
+pub(crate) const WATCHED: Option<&[&str]> = Some(&["/etc/default/init"]);
+#[cfg(any(target_os = "aix", all(target_arch = "wasm32", target_os = "wasi")))]
+pub(crate) const WATCHED: Option<&[&str]> = Some(&[]);
+#[cfg(not(any(
+    all(target_os = "linux", not(target_env = "ohos")),
+    target_os = "hurd",
+    target_os = "freebsd",
+    target_os = "dragonfly",
+    target_os = "netbsd",
+    target_os = "openbsd",
+    target_vendor = "apple",
+    target_os = "illumos",
+    target_os = "solaris",
+    target_os = "aix",
+    all(target_arch = "wasm32", target_os = "wasi"),
+)))]
+pub(crate) const WATCHED: Option<&[&str]> = None;
+
+/// How long a time zone is reused if the platform has no [`WATCHED`] files.
+const MAX_AGE: Duration = Duration::from_secs(1);
+
+/// A handle to the current time zone that only detects it again if its source changed.
+///
+/// [`get_timezone()`](crate::get_timezone) reads the system configuration on every call. This is
+/// cheap enough for most programs, but not for hot paths, e.g. a logger that stamps every line
+/// with the time zone. `CachedTimezone` remembers the detected time zone, and only detects it
+/// again if the `TZ` or `TZDIR` environment variable or the metadata of one of the configuration
+/// files changed, e.g. the modification time or the inode of `/etc/localtime`, `/etc/timezone`
+/// or `/etc/config/system` on Linux, or if a location of the zoneinfo database was added with
+/// [`register_zoneinfo_prefix()`](crate::register_zoneinfo_prefix).
+///
+/// On Windows, Android, OpenHarmony, Haiku and in web browsers the time zone is read from an API
+/// that cannot be observed, so the cached time zone is reused for at most one second.
+///
+/// Errors are not cached, so the next call detects the time zone again.
+///
+/// ```rust
+/// use iana_time_zone::CachedTimezone;
+///
+/// let cache = CachedTimezone::new();
+/// for _ in 0..1000 {
+///     let tz_str = cache.get()?;
+///     assert!(!tz_str.is_empty());
+/// }
+/// # Ok::<(), iana_time_zone::GetTimezoneError>(())
+/// ```
+#[derive(Debug, Default)]
+pub struct CachedTimezone {
+    entry: Mutex<Option<Entry>>,
+}
+
+#[derive(Debug)]
+struct Entry {
+    stamp: Stamp,
+    /// When the time zone was detected, if the platform has a clock.
+    detected_at: Option<Instant>,
+    name: Arc<str>,
+}
+
+impl CachedTimezone {
+    /// Create an empty cache. The time zone is detected on the first call of [`get()`](Self::get).
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Get the current IANA time zone, and only detect it again if its source changed.
+    pub fn get(&self) -> Result<Arc<str>, GetTimezoneError> {
+        #[cfg(feature = "testing")]
+        if let Some(detection) = crate::testing::overridden() {
+            return Ok(detection.name.into());
+        }
+
+        // The stamp is taken before the detection. If the source changes in between, then the
+        // next call sees a different stamp, and detects the time zone again.
+        let stamp = Stamp::new(Path::new("/"), WATCHED.unwrap_or_default());
+        let mut entry = self.entry.lock().unwrap_or_else(PoisonError::into_inner);
+        if let Some(entry) = &*entry {
+            if entry.stamp == stamp && (WATCHED.is_some() || !expired(entry.detected_at)) {
+                return Ok(Arc::clone(&entry.name));
+            }
+        }
+
+        let name: Arc<str> = crate::get_timezone()?.into();
+        *entry = Some(Entry {
+            stamp,
+            detected_at: now(),
+            name: Arc::clone(&name),
+        });
+        Ok(name)
+    }
+}
+
+static GLOBAL: Lazy<CachedTimezone> = Lazy::new(CachedTimezone::new);
+
+/// Get the current IANA time zone from a process-wide [`CachedTimezone`].
+///
+/// ```rust
+/// let tz_str = iana_time_zone::get_timezone_cached()?;
+/// println!("The current time zone is: {}", tz_str);
+/// # Ok::<(), iana_time_zone::GetTimezoneError>(())
+/// ```
+pub fn get_timezone_cached() -> Result<Arc<str>, GetTimezoneError> {
+    GLOBAL.get().get()
+}
+
+/// The state of the sources of the time zone, which changes if they are changed.
+#[derive(Debug, PartialEq, Eq)]
+struct Stamp {
+    tz: Option<OsString>,
+    /// `TZDIR` and the registered prefixes change which zoneinfo database names are looked up in.
+    tzdir: Option<OsString>,
+    #[cfg(unix)]
+    prefixes: usize,
+    files: Vec<(Option<FileStamp>, Option<FileStamp>)>,
+}
+
+impl Stamp {
+    /// Stamp the `TZ` and `TZDIR` environment variables, the registered zoneinfo prefixes, and
+    /// the absolute `files` inside `root`.
+    ///
+    /// Both a symlink and its final target are stamped, so replacing either of them is noticed.
+    fn new(root: &Path, files: &[&str]) -> Self {
+        let files = files
+            .iter()
+            .map(|file| {
+                let path = root.join(file.trim_start_matches('/'));
+                (
+                    fs::symlink_metadata(&path).ok().map(FileStamp::new),
+                    fs::metadata(&path).ok().map(FileStamp::new),
+                )
+            })
+            .collect();
+        Self {
+            tz: env::var_os("TZ"),
+            tzdir: env::var_os("TZDIR"),
+            #[cfg(unix)]
+            prefixes: crate::unix::registered_prefixes(),
+            files,
+        }
+    }
+}
+
+/// The metadata of a file that changes if the file is modified or replaced.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct FileStamp {
+    modified: Option<SystemTime>,
+    len: u64,
+    #[cfg(unix)]
+    dev_ino: (u64, u64),
+}
+
+impl FileStamp {
+    fn new(metadata: fs::Metadata) -> Self {
+        #[cfg(unix)]
+        use std::os::unix::fs::MetadataExt;
+
+        Self {
+            modified: metadata.modified().ok(),
+            len: metadata.len(),
+            #[cfg(unix)]
+            dev_ino: (metadata.dev(), metadata.ino()),
+        }
+    }
+}
+
+/// The current time, unless the platform has no clock.
+fn now() -> Option<Instant> {
+    if cfg!(all(target_arch = "wasm32", target_os = "unknown")) {
+        None
+    } else {
+        Some(Instant::now())
+    }
+}
+
+fn expired(detected_at: Option<Instant>) -> bool {
+    detected_at.map_or(true, |instant| instant.elapsed() >= MAX_AGE)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+    use std::sync::Arc;
+
+    use super::{get_timezone_cached, CachedTimezone, Stamp};
+
+    #[test]
+    fn test_cached() {
+        let cache = CachedTimezone::new();
+        let first = cache.get().unwrap();
+        let second = cache.get().unwrap();
+        assert!(Arc::ptr_eq(&first, &second));
+        assert_eq!(&*first, crate::get_timezone().unwrap());
+        assert_eq!(get_timezone_cached().unwrap(), first);
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn test_stamp() {
+        use std::os::unix::fs::symlink;
+
+        let root =
+            std::env::temp_dir().join(format!("iana-time-zone-stamp-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+        fs::create_dir_all(root.join("etc")).unwrap();
+        let files = &["/etc/localtime", "/etc/timezone"];
+
+        let missing = Stamp::new(&root, files);
+        assert_eq!(Stamp::new(&root, files), missing);
+
+        fs::write(root.join("etc/timezone"), "Europe/Berlin\n").unwrap();
+        let written = Stamp::new(&root, files);
+        assert_ne!(written, missing);
+        assert_eq!(Stamp::new(&root, files), written);
+
+        // Replace a file atomically, like `timedatectl set-timezone` does.
+        fs::write(root.join("etc/timezone.tmp"), "Europe/Paris\n").unwrap();
+        fs::rename(root.join("etc/timezone.tmp"), root.join("etc/timezone")).unwrap();
+        assert_ne!(Stamp::new(&root, files), written);
+
+        symlink(
+            "/usr/share/zoneinfo/Europe/Berlin",
+            root.join("etc/localtime"),
+        )
+        .unwrap();
+        let linked = Stamp::new(&root, files);
+        symlink(
+            "/usr/share/zoneinfo/Europe/Paris",
+            root.join("etc/localtime.tmp"),
+        )
+        .unwrap();
+        fs::rename(root.join("etc/localtime.tmp"), root.join("etc/localtime")).unwrap();
+        let renamed = Stamp::new(&root, files);
+        assert_ne!(renamed, linked);
+
+        // A new location of the zoneinfo database may resolve the names differently.
+        crate::register_zoneinfo_prefix("/opt/iana-time-zone-stamp/zoneinfo");
+        assert_ne!(Stamp::new(&root, files), renamed);
+
+        fs::remove_dir_all(&root).unwrap();
+    }
+}
@@ -0,0 +1,61 @@
+//! Integration with the crate `chrono-tz`.
+
+use chrono_tz::Tz;
+
+use crate::{aliases, canonicalize, GetTimezoneError};
+
+/// Get the current time zone as a [`chrono_tz::Tz`].
+///
+/// The version of the time zone database in `chrono-tz` may differ from the one of the system,
+/// so if the detected name is unknown to `chrono-tz`, then its primary name and the backward
+/// compatible aliases of that are tried, too, e.g. `"Europe/Kiev"` for `"Europe/Kyiv"`. If none
+/// of them is known, then [`GetTimezoneError::InvalidTimezone`] is returned.
+///
+/// The feature `chrono-tz` must be enabled.
+///
+/// ```rust
+/// let tz = iana_time_zone::get_chrono_tz()?;
+/// println!("The current time zone is: {}", tz);
+/// # Ok::<(), iana_time_zone::GetTimezoneError>(())
+/// ```
+pub fn get_chrono_tz() -> Result<Tz, GetTimezoneError> {
+    to_chrono_tz(crate::get_timezone()?)
+}
+
+fn to_chrono_tz(name: String) -> Result<Tz, GetTimezoneError> {
+    if let Ok(tz) = name.parse() {
+        return Ok(tz);
+    }
+    let primary = canonicalize(&name);
+    let found = Some(primary)
+        .into_iter()
+        .chain(aliases(primary))
+        .find_map(|name| name.parse().ok());
+    found.ok_or(GetTimezoneError::InvalidTimezone(name))
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono_tz::Tz;
+
+    use super::{get_chrono_tz, to_chrono_tz};
+    use crate::GetTimezoneError;
+
+    #[test]
+    fn test_get_chrono_tz() {
+        let tz = get_chrono_tz().unwrap();
+        assert_eq!(tz, crate::get_timezone().unwrap().parse::<Tz>().unwrap());
+    }
+
+    #[test]
+    fn test_to_chrono_tz() {
+        let tz = |name: &str| to_chrono_tz(name.to_owned());
+        assert_eq!(tz("Europe/Berlin").unwrap(), Tz::Europe__Berlin);
+        // Aliases are kept if chrono-tz knows them.
+        assert_eq!(tz("Asia/Calcutta").unwrap(), Tz::Asia__Calcutta);
+        assert!(matches!(
+            tz("Mars/Olympus_Mons"),
+            Err(GetTimezoneError::InvalidTimezone(name)) if name == "Mars/Olympus_Mons"
+        ));
+    }
+}
@@ -0,0 +1,137 @@
+//! A report of how the time zone was detected.
+
+use std::fmt;
+use std::path::PathBuf;
+
+use crate::GetTimezoneError;
+
+/// The result of [`detect()`](crate::detect): the time zone, and where it was found.
+#[derive(Debug)]
+#[non_exhaustive]
+pub struct Detection {
+    /// The IANA time zone identifier, e.g. `"Europe/Berlin"`.
+    ///
+    /// If the source contained a POSIX TZ rule string, then this is the preferred IANA time zone
+    /// with the same rules, or the rule itself if no time zone matches it, see [`posix_tz`].
+    ///
+    /// [`posix_tz`]: Self::posix_tz
+    pub name: String,
+    /// The kind of source the time zone was read from.
+    pub source: Source,
+    /// The file that was read, if the source is a file.
+    pub path: Option<PathBuf>,
+    /// The time zone is from the `right/` variant of the zoneinfo database.
+    ///
+    /// In this variant, the time stamps count leap seconds, i.e. they are TAI-10 instead of UTC,
+    /// and have to be interpreted accordingly.
+    pub leap_seconds: bool,
+    /// The POSIX TZ rule string that the source contained, if `name` was inferred from it.
+    ///
+    /// A rule can match multiple time zones, see [`PosixTz::iana_candidates()`], so `name` is
+    /// only the preferred one of them. The C library uses the rule, so it is the authoritative
+    /// description of the time zone.
+    ///
+    /// [`PosixTz::iana_candidates()`]: crate::PosixTz::iana_candidates
+    pub posix_tz: Option<String>,
+    /// The errors of every source that was tried before `source`, in order.
+    pub errors: Vec<(Source, GetTimezoneError)>,
+}
+
+impl Detection {
+    /// The time zone `name`, which was read from a source of the kind `source`.
+    ///
+    /// This is for implementations of [`TimezoneSource`](crate::TimezoneSource). The `posix/`
+    /// and `right/` directories of the zoneinfo database are stripped from `name`.
+    pub fn new(mut name: String, source: Source) -> Self {
+        let mut leap_seconds = false;
+        if name.starts_with("posix/") {
+            name.replace_range(.."posix/".len(), "");
+        } else if name.starts_with("right/") {
+            name.replace_range(.."right/".len(), "");
+            leap_seconds = true;
+        }
+        Self {
+            name,
+            source,
+            path: None,
+            leap_seconds,
+            posix_tz: None,
+            errors: Vec::new(),
+        }
+    }
+
+    /// The name of the time zone, or [`GetTimezoneError::PosixRule`] if it was inferred from a
+    /// POSIX TZ rule string, for the functions that cannot report [`posix_tz`](Self::posix_tz).
+    pub(crate) fn into_name(self) -> Result<String, GetTimezoneError> {
+        match self.posix_tz {
+            Some(rule) => Err(GetTimezoneError::PosixRule(rule)),
+            None => Ok(self.name),
+        }
+    }
+
+    pub(crate) fn with_path(mut self, path: impl Into<PathBuf>) -> Self {
+        self.path = Some(path.into());
+        self
+    }
+}
+
+/// The kind of source a time zone was read from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Source {
+    /// The `TZ` environment variable.
+    EnvVar,
+    /// A symlink into the zoneinfo database, e.g. `/etc/localtime`.
+    Symlink,
+    /// A configuration file containing the name, e.g. `/etc/timezone`.
+    File,
+    /// A copy of a file from the zoneinfo database, e.g. `/etc/localtime`, that was identified
+    /// by its contents.
+    FileCopy,
+    /// The OpenWrt UCI configuration `/etc/config/system`.
+    UciConfig,
+    /// An API of the operating system or the runtime environment.
+    OsApi,
+    /// A fixed default, because the platform has no time zone configuration.
+    Default,
+    /// A source that was added to a [`Detector`](crate::Detector), with a short description.
+    Custom(&'static str),
+    /// An override for testing, see the feature `testing`.
+    Override,
+}
+
+impl fmt::Display for Source {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Source::EnvVar => "environment variable",
+            Source::Symlink => "symlink",
+            Source::File => "file",
+            Source::FileCopy => "file copy",
+            Source::UciConfig => "UCI config",
+            Source::OsApi => "OS API",
+            Source::Default => "default",
+            Source::Custom(name) => name,
+            Source::Override => "override",
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Detection, Source};
+
+    #[test]
+    fn test_variants() {
+        let detection = Detection::new("Europe/Paris".to_owned(), Source::Symlink);
+        assert_eq!(detection.name, "Europe/Paris");
+        assert!(!detection.leap_seconds);
+
+        let detection = Detection::new("posix/Europe/Paris".to_owned(), Source::Symlink);
+        assert_eq!(detection.name, "Europe/Paris");
+        assert!(!detection.leap_seconds);
+
+        let detection = Detection::new("right/Europe/Paris".to_owned(), Source::Symlink);
+        assert_eq!(detection.name, "Europe/Paris");
+        assert!(detection.leap_seconds);
+    }
+}
@@ -0,0 +1,682 @@
+//! A configurable pipeline of the sources that the time zone is read from.
+
+use std::fmt;
+use std::mem;
+use std::path::{Path, PathBuf};
+
+use crate::filesystem::{FileSystem, StdFs};
+use crate::tzdb::is_valid_timezone;
+use crate::{Detection, GetTimezoneError, PosixTz, Source};
+
+/// A place to read the time zone from, e.g. a configuration file or an API of the operating
+/// system.
+///
+/// The sources of this crate are available as [`BuiltinSource`]s. Applications can implement
+/// the trait to add their own sources to a [`Detector`], e.g. a value of their configuration.
+/// It is also implemented for a pair of a [`Source`] and a closure.
+///
+/// ```rust
+/// use std::path::Path;
+///
+/// use iana_time_zone::{Detection, Detector, GetTimezoneError, Source, TimezoneSource};
+///
+/// struct AppConfig {
+///     timezone: String,
+/// }
+///
+/// impl TimezoneSource for AppConfig {
+///     fn kind(&self) -> Source {
+///         Source::Custom("app config")
+///     }
+///
+///     fn detect(&self, _root: &Path) -> Result<Detection, GetTimezoneError> {
+///         Ok(Detection::new(self.timezone.clone(), self.kind()))
+///     }
+/// }
+///
+/// let config = AppConfig {
+///     timezone: "Europe/Berlin".to_owned(),
+/// };
+/// // Prefer the configuration of the application over the sources of the system.
+/// let detector = Detector::new().first(config);
+/// assert_eq!(detector.get_timezone()?, "Europe/Berlin");
+/// # Ok::<(), iana_time_zone::GetTimezoneError>(())
+/// ```
+pub trait TimezoneSource: Send + Sync {
+    /// The kind of this source, which is reported with its error in [`Detection::errors`].
+    fn kind(&self) -> Source;
+
+    /// Read the time zone.
+    ///
+    /// `root` is the root of the file system to inspect, which is `/` unless the [`Detector`]
+    /// was told otherwise. Sources that do not read files ignore it.
+    fn detect(&self, root: &Path) -> Result<Detection, GetTimezoneError>;
+}
+
+impl<F> TimezoneSource for (Source, F)
+where
+    F: Fn(&Path) -> Result<Detection, GetTimezoneError> + Send + Sync,
+{
+    fn kind(&self) -> Source {
+        self.0
+    }
+
+    fn detect(&self, root: &Path) -> Result<Detection, GetTimezoneError> {
+        (self.1)(root)
+    }
+}
+
+/// A source of the time zone that is implemented by this crate.
+///
+/// The sources that [`detect()`](crate::detect) tries on the current platform, in order, are
+/// [`BuiltinSource::defaults()`].
+#[derive(Clone, Copy)]
+pub struct BuiltinSource {
+    name: &'static str,
+    kind: Source,
+    detect: fn(&dyn FileSystem, &Path) -> Result<Detection, GetTimezoneError>,
+}
+
+#[cfg(unix)]
+impl BuiltinSource {
+    /// The `TZ` environment variable, interpreted like the C library does.
+    ///
+    /// An unset `TZ` selects the system default, so the next source is tried. POSIX TZ rule
+    /// strings are resolved to the preferred IANA time zone with the same rules, or kept if no
+    /// time zone matches them, see [`Detection::posix_tz`].
+    pub const TZ_ENV: Self = Self {
+        name: "TZ",
+        kind: Source::EnvVar,
+        detect: |fs, _| crate::unix::tz_env(fs),
+    };
+
+    /// The symlink `/etc/localtime` into the zoneinfo database.
+    pub const ETC_LOCALTIME: Self = Self {
+        name: "/etc/localtime",
+        kind: Source::Symlink,
+        detect: crate::unix::etc_localtime,
+    };
+
+    /// The file `/etc/timezone` of Debian and its derivatives.
+    pub const ETC_TIMEZONE: Self = Self {
+        name: "/etc/timezone",
+        kind: Source::File,
+        detect: crate::unix::etc_timezone,
+    };
+
+    /// The UCI configuration `/etc/config/system` of OpenWrt.
+    pub const OPENWRT: Self = Self {
+        name: "/etc/config/system",
+        kind: Source::UciConfig,
+        detect: crate::unix::openwrt::etc_config_system,
+    };
+
+    /// The file `/var/db/zoneinfo` of FreeBSD and DragonFly BSD.
+    pub const VAR_DB_ZONEINFO: Self = Self {
+        name: "/var/db/zoneinfo",
+        kind: Source::File,
+        detect: crate::unix::var_db_zoneinfo,
+    };
+
+    /// The `TZ` setting in `/etc/default/init` of illumos and Solaris.
+    pub const ETC_DEFAULT_INIT: Self = Self {
+        name: "/etc/default/init",
+        kind: Source::File,
+        detect: crate::unix::etc_default_init,
+    };
+
+    /// A copy of a file of the zoneinfo database at `/etc/localtime`, which is identified by
+    /// comparing it to all files of the database.
+    ///
+    /// This is slow, so it should be the last source.
+    pub const ETC_LOCALTIME_COPY: Self = Self {
+        name: "/etc/localtime (copy)",
+        kind: Source::FileCopy,
+        detect: crate::zoneinfo::etc_localtime_copy,
+    };
+}
+
+#[cfg(not(any(
+    all(target_os = "linux", not(target_env = "ohos")),
+    target_os = "hurd",
+    target_os = "freebsd",
+    target_os = "dragonfly",
+    target_os = "netbsd",
+    target_os = "openbsd",
+    target_os = "illumos",
+    target_os = "solaris",
+    target_os = "aix",
+    all(target_arch = "wasm32", target_os = "wasi"),
+)))]
+impl BuiltinSource {
+    /// The API of the operating system or the runtime environment, e.g. `Calendar` on Windows,
+    /// `CFTimeZoneCopySystem()` on Apple platforms, or `Intl.DateTimeFormat()` in a browser.
+    ///
+    /// Only available on the platforms that do not read the time zone from files.
+    pub const OS_API: Self = Self {
+        name: "OS API",
+        kind: Source::OsApi,
+        detect: |_, _| crate::platform::detect_inner(),
+    };
+}
+
+#[cfg(all(target_arch = "wasm32", target_os = "wasi"))]
+impl BuiltinSource {
+    /// The `TZ` environment variable, which WASI passes on from the host.
+    ///
+    /// An unset `TZ` selects the next source. POSIX TZ rule strings are resolved to the
+    /// preferred IANA time zone with the same rules.
+    pub const TZ_ENV: Self = Self {
+        name: "TZ",
+        kind: Source::EnvVar,
+        detect: |_, _| crate::platform::tz_env(),
+    };
+
+    /// UTC, because WASI has no other configuration of the time zone.
+    pub const DEFAULT: Self = Self {
+        name: "default",
+        kind: Source::Default,
+        detect: |_, _| crate::platform::utc(),
+    };
+}
+
+impl BuiltinSource {
+    /// The sources that [`detect()`](crate::detect) tries on the current platform, in order.
+    pub fn defaults() -> &'static [Self] {
+        crate::platform::SOURCES
+    }
+
+    /// The sources that [`detect_in_root()`](crate::detect_in_root) tries, in order.
+    #[cfg(unix)]
+    pub fn in_root() -> &'static [Self] {
+        crate::unix::IN_ROOT
+    }
+
+    /// A short description of the source, e.g. `"/etc/localtime"`.
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+}
+
+impl TimezoneSource for BuiltinSource {
+    fn kind(&self) -> Source {
+        self.kind
+    }
+
+    fn detect(&self, root: &Path) -> Result<Detection, GetTimezoneError> {
+        (self.detect)(&StdFs, root)
+    }
+}
+
+impl PartialEq for BuiltinSource {
+    fn eq(&self, other: &Self) -> bool {
+        self.name == other.name
+    }
+}
+
+impl Eq for BuiltinSource {}
+
+impl fmt::Debug for BuiltinSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("BuiltinSource").field(&self.name).finish()
+    }
+}
+
+/// A builder of a custom pipeline of the sources that the time zone is read from.
+///
+/// [`detect()`](crate::detect) tries a fixed list of sources in an order that depends on the
+/// platform, e.g. `TZ`, `/etc/localtime`, `/etc/timezone` and `/etc/config/system` on Linux.
+/// A `Detector` starts with the same list, which can be reordered, shortened, or extended with
+/// sources of the application. Like in `detect()`, the first source that contains an IANA time
+/// zone, or a POSIX TZ rule string that matches one, wins.
+///
+/// ```rust
+/// use std::path::Path;
+///
+/// use iana_time_zone::{BuiltinSource, Detection, Detector, Source};
+///
+/// // Use a fixed time zone, e.g. in a test, if the system has none.
+/// let detector = Detector::new().source((Source::Custom("fixture"), |_: &Path| {
+///     Ok(Detection::new("Asia/Tokyo".to_owned(), Source::Custom("fixture")))
+/// }));
+/// println!("{}", detector.get_timezone()?);
+///
+/// // Ignore the environment variable `TZ`.
+/// # #[cfg(unix)]
+/// let detector = Detector::new().without(BuiltinSource::TZ_ENV);
+/// # Ok::<(), iana_time_zone::GetTimezoneError>(())
+/// ```
+pub struct Detector {
+    sources: Vec<Entry>,
+    root: PathBuf,
+    canonicalize: bool,
+}
+
+enum Entry {
+    Builtin(BuiltinSource),
+    Custom(Box<dyn TimezoneSource>),
+}
+
+impl Strategy for Entry {
+    fn kind(&self) -> Source {
+        match self {
+            Entry::Builtin(source) => source.kind,
+            Entry::Custom(source) => source.kind(),
+        }
+    }
+
+    fn run(&self, fs: &dyn FileSystem, root: &Path) -> Result<Detection, GetTimezoneError> {
+        match self {
+            Entry::Builtin(source) => source.run(fs, root),
+            // Custom sources read the real file system, if they read files at all.
+            Entry::Custom(source) => source.detect(root),
+        }
+    }
+}
+
+impl Detector {
+    /// A detector with the sources that [`detect()`](crate::detect) uses on this platform.
+    pub fn new() -> Self {
+        let mut detector = Self::empty();
+        detector.sources = BuiltinSource::defaults()
+            .iter()
+            .map(|&source| Entry::Builtin(source))
+            .collect();
+        detector
+    }
+
+    /// A detector with the sources that
+    /// [`detect_in_root()`](crate::detect_in_root) uses to inspect the file system at `root`.
+    #[cfg(unix)]
+    pub fn in_root(root: impl Into<PathBuf>) -> Self {
+        let mut detector = Self::empty().root(root);
+        detector.sources = BuiltinSource::in_root()
+            .iter()
+            .map(|&source| Entry::Builtin(source))
+            .collect();
+        detector
+    }
+
+    /// A detector without sources, to add them in the order they should be tried.
+    pub fn empty() -> Self {
+        Self {
+            sources: Vec::new(),
+            root: PathBuf::from("/"),
+            canonicalize: false,
+        }
+    }
+
+    /// Add `source` after the other sources.
+    pub fn source(mut self, source: impl TimezoneSource + 'static) -> Self {
+        self.sources.push(Entry::Custom(Box::new(source)));
+        self
+    }
+
+    /// Add `source` before the other sources.
+    pub fn first(mut self, source: impl TimezoneSource + 'static) -> Self {
+        self.sources.insert(0, Entry::Custom(Box::new(source)));
+        self
+    }
+
+    /// Add the built-in `source` after the other sources.
+    ///
+    /// Unlike with [`source()`](Self::source), it can be removed again with
+    /// [`without()`](Self::without).
+    pub fn builtin(mut self, source: BuiltinSource) -> Self {
+        self.sources.push(Entry::Builtin(source));
+        self
+    }
+
+    /// Remove the built-in `source`.
+    pub fn without(mut self, source: BuiltinSource) -> Self {
+        self.sources
+            .retain(|entry| !matches!(entry, Entry::Builtin(builtin) if *builtin == source));
+        self
+    }
+
+    /// Inspect the file system at `root` instead of the running system.
+    ///
+    /// Absolute symlinks are interpreted relative to `root`. Sources that do not read files,
+    /// e.g. [`BuiltinSource::TZ_ENV`], still read the running system.
+    #[cfg(unix)]
+    pub fn root(mut self, root: impl Into<PathBuf>) -> Self {
+        self.root = root.into();
+        self
+    }
+
+    /// Replace backward compatible aliases like `"Asia/Calcutta"` with their primary name, see
+    /// [`canonicalize()`](crate::canonicalize).
+    pub fn canonicalize(mut self, canonicalize: bool) -> Self {
+        self.canonicalize = canonicalize;
+        self
+    }
+
+    /// Try all sources in order, and report the first time zone that was found.
+    ///
+    /// If all sources fail, then the error of the first source that is broken is returned, see
+    /// [`GetTimezoneError::is_not_configured()`]. If no source is configured, then the error of
+    /// the last one is returned, or [`GetTimezoneError::Unsupported`] if there are no sources.
+    pub fn detect(&self) -> Result<Detection, GetTimezoneError> {
+        let mut detection = first_of(&StdFs, &self.root, &self.sources)?;
+        if self.canonicalize {
+            let name = crate::canonicalize(&detection.name);
+            if name != detection.name {
+                detection.name = name.to_owned();
+            }
+        }
+        Ok(detection)
+    }
+
+    /// Try all sources in order, and return the first time zone that was found.
+    ///
+    /// Like [`get_timezone()`](crate::get_timezone), this fails with
+    /// [`GetTimezoneError::PosixRule`] if the source contained a POSIX TZ rule string.
+    pub fn get_timezone(&self) -> Result<String, GetTimezoneError> {
+        self.detect().and_then(Detection::into_name)
+    }
+}
+
+impl Default for Detector {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl fmt::Debug for Detector {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let sources = self
+            .sources
+            .iter()
+            .map(|entry| match entry {
+                Entry::Builtin(source) => source.name(),
+                Entry::Custom(_) => "custom",
+            })
+            .collect::<Vec<_>>();
+        f.debug_struct("Detector")
+            .field("sources", &sources)
+            .field("root", &self.root)
+            .field("canonicalize", &self.canonicalize)
+            .finish()
+    }
+}
+
+/// A source that reads the file system through a [`FileSystem`], so it can be tested against
+/// the layouts of other operating systems.
+pub(crate) trait Strategy {
+    fn kind(&self) -> Source;
+
+    fn run(&self, fs: &dyn FileSystem, root: &Path) -> Result<Detection, GetTimezoneError>;
+}
+
+impl Strategy for BuiltinSource {
+    fn kind(&self) -> Source {
+        self.kind
+    }
+
+    fn run(&self, fs: &dyn FileSystem, root: &Path) -> Result<Detection, GetTimezoneError> {
+        (self.detect)(fs, root)
+    }
+}
+
+/// Try all `sources` in order, and return the first valid time zone that was found.
+///
+/// A POSIX TZ rule string is replaced with the preferred IANA time zone with the same rules, or
+/// kept if none matches it, and other names that are not IANA time zones are skipped with a
+/// [`GetTimezoneError::InvalidTimezone`]. If all sources fail, then the error of the first
+/// broken source is returned, or the error of the last one if none is configured.
+pub(crate) fn first_of<'a, S: Strategy + 'a>(
+    fs: &dyn FileSystem,
+    root: &Path,
+    sources: impl IntoIterator<Item = &'a S>,
+) -> Result<Detection, GetTimezoneError> {
+    let mut errors = Vec::new();
+    for source in sources {
+        match source
+            .run(fs, root)
+            .and_then(|detection| validate(fs, root, source.kind(), detection))
+        {
+            Ok(mut detection) => {
+                detection.errors = errors;
+                return Ok(detection);
+            }
+            Err(err) => errors.push((source.kind(), err)),
+        }
+    }
+    // A broken source is more interesting than the sources that are not configured.
+    let idx = errors
+        .iter()
+        .position(|(_, err)| !err.is_not_configured())
+        .or_else(|| errors.len().checked_sub(1));
+    match idx {
+        Some(idx) => Err(errors.swap_remove(idx).1),
+        None => Err(GetTimezoneError::Unsupported),
+    }
+}
+
+/// Check that the name of `detection` is an IANA time zone, or resolve it if it is a POSIX TZ rule.
+///
+/// The APIs of operating systems are trusted to return IANA time zones, even if they are newer
+/// than the embedded database. A rule is configured like a name, and the C library uses it, so
+/// it is kept even if no time zone matches it, instead of trying the next source.
+fn validate(
+    fs: &dyn FileSystem,
+    root: &Path,
+    kind: Source,
+    mut detection: Detection,
+) -> Result<Detection, GetTimezoneError> {
+    if kind == Source::OsApi || is_known_zone(fs, root, &detection.name) {
+        return Ok(detection);
+    }
+    let rule = match PosixTz::parse(&detection.name) {
+        Ok(rule) => rule,
+        Err(_) => return Err(GetTimezoneError::InvalidTimezone(detection.name)),
+    };
+    detection.posix_tz = Some(match rule.iana_candidates().first() {
+        Some(name) => mem::replace(&mut detection.name, (*name).to_owned()),
+        None => detection.name.clone(),
+    });
+    Ok(detection)
+}
+
+/// Check if `name` is an IANA time zone.
+///
+/// The installed zoneinfo database may be newer than the embedded list of names, so a name is
+/// also accepted if it is a TZif file in the database.
+fn is_known_zone(fs: &dyn FileSystem, root: &Path, name: &str) -> bool {
+    #[cfg(unix)]
+    let installed = || crate::unix::zoneinfo_file(fs, root, name).is_some();
+    #[cfg(not(unix))]
+    let installed = || {
+        let _ = (fs, root);
+        false
+    };
+    is_valid_timezone(name) || installed()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use super::{BuiltinSource, Detector};
+    use crate::{Context, Detection, GetTimezoneError, Source};
+
+    fn not_configured() -> GetTimezoneError {
+        GetTimezoneError::NotConfigured {
+            context: Context::EnvVar("TZ"),
+        }
+    }
+
+    #[test]
+    fn test_first_of() {
+        let detection = Detector::empty()
+            .source((Source::EnvVar, |_: &Path| Err(not_configured())))
+            .source((Source::File, |root: &Path| {
+                Ok(Detection::new("Europe/Berlin".to_owned(), Source::File)
+                    .with_path(root.join("tz")))
+            }))
+            .source((Source::Default, |_: &Path| unreachable!()))
+            .detect()
+            .unwrap();
+        assert_eq!(detection.name, "Europe/Berlin");
+        assert_eq!(detection.source, Source::File);
+        assert_eq!(detection.path.unwrap(), Path::new("/tz"));
+        assert!(matches!(
+            detection.errors[..],
+            [(Source::EnvVar, GetTimezoneError::NotConfigured { .. })],
+        ));
+
+        let err = Detector::empty()
+            .source((Source::EnvVar, |_: &Path| Err(not_configured())))
+            .source((Source::File, |_: &Path| {
+                Err(GetTimezoneError::parse(Path::new("/tz"), "garbage"))
+            }))
+            .detect()
+            .unwrap_err();
+        assert!(matches!(
+            err,
+            GetTimezoneError::FailedParsingString {
+                context: Context::Path(path),
+                ..
+            } if path == Path::new("/tz"),
+        ));
+        assert!(matches!(
+            Detector::empty().detect(),
+            Err(GetTimezoneError::Unsupported),
+        ));
+
+        // A broken source is reported instead of the sources that are not configured.
+        let err = Detector::empty()
+            .source((Source::File, |_: &Path| {
+                Ok(Detection::new("Mars/Olympus_Mons".to_owned(), Source::File))
+            }))
+            .source((Source::EnvVar, |_: &Path| Err(not_configured())))
+            .detect()
+            .unwrap_err();
+        assert!(
+            matches!(err, GetTimezoneError::InvalidTimezone(name) if name == "Mars/Olympus_Mons")
+        );
+
+        // Names that are not IANA time zones are skipped.
+        let detection = Detector::empty()
+            .source((Source::File, |_: &Path| {
+                Ok(Detection::new("# garbage".to_owned(), Source::File))
+            }))
+            .source((Source::File, |_: &Path| {
+                Ok(Detection::new("Europe/Berlin".to_owned(), Source::File))
+            }))
+            .detect()
+            .unwrap();
+        assert_eq!(detection.name, "Europe/Berlin");
+        assert!(matches!(
+            &detection.errors[..],
+            [(Source::File, GetTimezoneError::InvalidTimezone(name))] if name == "# garbage",
+        ));
+
+        // POSIX TZ rule strings are resolved.
+        let detection = Detector::empty()
+            .source((Source::UciConfig, |_: &Path| {
+                Ok(Detection::new(
+                    "EST5EDT,M3.2.0,M11.1.0".to_owned(),
+                    Source::UciConfig,
+                ))
+            }))
+            .detect()
+            .unwrap();
+        assert_eq!(detection.name, "America/New_York");
+        assert_eq!(detection.posix_tz.unwrap(), "EST5EDT,M3.2.0,M11.1.0");
+
+        // A rule of many zones is resolved to a representative one, and reported.
+        let detection = Detector::empty()
+            .source((Source::EnvVar, |_: &Path| {
+                Ok(Detection::new(
+                    "CET-1CEST,M3.5.0,M10.5.0/3".to_owned(),
+                    Source::EnvVar,
+                ))
+            }))
+            .detect()
+            .unwrap();
+        assert_eq!(detection.name, "Europe/Brussels");
+        assert_eq!(detection.posix_tz.unwrap(), "CET-1CEST,M3.5.0,M10.5.0/3");
+
+        // A rule that matches no zone is kept, because the C library uses it, and the name is
+        // not replaced silently.
+        let detector = Detector::empty()
+            .source((Source::EnvVar, |_: &Path| {
+                Ok(Detection::new(
+                    "EST5EDT,M4.1.0,M10.5.0".to_owned(),
+                    Source::EnvVar,
+                ))
+            }))
+            .source((Source::File, |_: &Path| {
+                Ok(Detection::new("Europe/Berlin".to_owned(), Source::File))
+            }));
+        let detection = detector.detect().unwrap();
+        assert_eq!(detection.name, "EST5EDT,M4.1.0,M10.5.0");
+        assert_eq!(detection.source, Source::EnvVar);
+        assert_eq!(detection.posix_tz.unwrap(), "EST5EDT,M4.1.0,M10.5.0");
+        assert!(matches!(
+            detector.get_timezone(),
+            Err(GetTimezoneError::PosixRule(rule)) if rule == "EST5EDT,M4.1.0,M10.5.0",
+        ));
+    }
+
+    #[test]
+    fn test_detector() {
+        let fixture = |_: &Path| Ok(Detection::new("Asia/Calcutta".to_owned(), Source::Default));
+        let detector = Detector::new().first((Source::Custom("fixture"), fixture));
+        assert_eq!(detector.get_timezone().unwrap(), "Asia/Calcutta");
+        let detector = detector.canonicalize(true);
+        assert_eq!(detector.get_timezone().unwrap(), "Asia/Kolkata");
+
+        // The defaults are the same as `detect()`, and can be removed one by one.
+        assert_eq!(
+            Detector::new().get_timezone().unwrap(),
+            crate::get_timezone().unwrap(),
+        );
+        let detector = BuiltinSource::defaults()
+            .iter()
+            .fold(Detector::new(), |detector, &source| {
+                detector.without(source)
+            });
+        assert!(matches!(
+            detector.detect(),
+            Err(GetTimezoneError::Unsupported)
+        ));
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn test_in_root() {
+        use std::fs;
+
+        let root =
+            std::env::temp_dir().join(format!("iana-time-zone-detector-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+        fs::create_dir_all(root.join("etc")).unwrap();
+        fs::write(root.join("etc/timezone"), "Europe/Paris\n").unwrap();
+
+        let detection = Detector::in_root(&root).detect().unwrap();
+        assert_eq!(detection.name, "Europe/Paris");
+        assert_eq!(detection.source, Source::File);
+
+        // Fall back to a setting of the application if the file system has no time zone.
+        let detector = Detector::empty()
+            .builtin(BuiltinSource::ETC_TIMEZONE)
+            .source((Source::Custom("app config"), |_: &Path| {
+                Ok(Detection::new(
+                    "Europe/Rome".to_owned(),
+                    Source::Custom("app config"),
+                ))
+            }))
+            .root(&root);
+        assert_eq!(detector.get_timezone().unwrap(), "Europe/Paris");
+        let detection = detector
+            .without(BuiltinSource::ETC_TIMEZONE)
+            .detect()
+            .unwrap();
+        assert_eq!(detection.name, "Europe/Rome");
+        assert_eq!(detection.source, Source::Custom("app config"));
+
+        fs::remove_dir_all(&root).unwrap();
+    }
+}
@@ -0,0 +1,376 @@
+//! A report of every source of the time zone, for bug reports.
+
+use std::cell::RefCell;
+use std::env;
+use std::fmt;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
+
+use crate::detector::first_of;
+use crate::filesystem::{FileSystem, StdFs};
+use crate::{BuiltinSource, Detection, GetTimezoneError};
+
+/// The number of lines of a text file that are shown.
+const MAX_LINES: usize = 16;
+/// The number of characters of a line that are shown.
+const MAX_LINE_LEN: usize = 100;
+
+/// Explain how the time zone is detected on this system.
+///
+/// Unlike [`detect()`](crate::detect), this tries every source of the platform, even after one
+/// of them found the time zone, and records the files that each source looked at: whether they
+/// exist, their symlink targets, and the beginning of their contents. The [`Display`](fmt::Display)
+/// implementation of the returned [`Diagnostics`] is meant to be pasted into a bug report.
+///
+/// ```rust
+/// println!("{}", iana_time_zone::diagnose());
+/// ```
+pub fn diagnose() -> Diagnostics {
+    Diagnostics {
+        result: crate::detect(),
+        root: None,
+        tz: env::var("TZ").ok(),
+        sources: diagnose_sources(&StdFs, Path::new("/"), BuiltinSource::defaults()),
+    }
+}
+
+/// Explain how the time zone configured in an alternate root file system is detected.
+///
+/// See [`diagnose()`] and [`detect_in_root()`](crate::detect_in_root). The `TZ` environment
+/// variable of the current process is not consulted.
+#[cfg(unix)]
+pub fn diagnose_in_root(root: &Path) -> Diagnostics {
+    Diagnostics {
+        result: crate::detect_in_root(root),
+        root: Some(root.to_owned()),
+        tz: None,
+        sources: diagnose_sources(&StdFs, root, BuiltinSource::in_root()),
+    }
+}
+
+/// The report of [`diagnose()`].
+#[derive(Debug)]
+#[non_exhaustive]
+pub struct Diagnostics {
+    /// The result of [`detect()`](crate::detect).
+    pub result: Result<Detection, GetTimezoneError>,
+    /// The alternate root file system that was inspected, see [`diagnose_in_root()`].
+    pub root: Option<PathBuf>,
+    /// The value of the environment variable `TZ`, if it is set and was consulted.
+    pub tz: Option<String>,
+    /// The results of all sources of the platform, in the order that they are tried.
+    pub sources: Vec<SourceDiagnostics>,
+}
+
+/// The result of a single source in [`Diagnostics`].
+#[derive(Debug)]
+#[non_exhaustive]
+pub struct SourceDiagnostics {
+    /// The source.
+    pub source: BuiltinSource,
+    /// What the source found, or why it was rejected.
+    pub result: Result<Detection, GetTimezoneError>,
+    /// The files that the source looked at, in order, and what they looked like.
+    pub files: Vec<(PathBuf, FileState)>,
+}
+
+/// What a file looked like when the source was run.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum FileState {
+    /// The file does not exist.
+    Missing,
+    /// The file is a symlink to the contained target.
+    Symlink(PathBuf),
+    /// The file is a directory.
+    Directory,
+    /// The file is a TZif file of the contained length in bytes.
+    Tzif(usize),
+    /// The file is a text file, e.g. a configuration file.
+    Text {
+        /// The length of the file in bytes.
+        len: usize,
+        /// The first lines of the file.
+        lines: Vec<String>,
+        /// The number of lines that were left out.
+        more_lines: usize,
+    },
+    /// The file is neither a TZif file nor text, and has the contained length in bytes.
+    Binary(usize),
+    /// The file could not be read.
+    Error(io::Error),
+}
+
+fn diagnose_sources(
+    fs: &dyn FileSystem,
+    root: &Path,
+    sources: &[BuiltinSource],
+) -> Vec<SourceDiagnostics> {
+    sources
+        .iter()
+        .map(|&source| {
+            let recording = RecordingFs {
+                fs,
+                paths: RefCell::new(Vec::new()),
+                listed: RefCell::new(Vec::new()),
+            };
+            let result = first_of(&recording, root, [source].iter());
+            let files = recording
+                .paths
+                .into_inner()
+                .into_iter()
+                .map(|path| {
+                    let state = inspect(fs, &path);
+                    (path, state)
+                })
+                .collect();
+            SourceDiagnostics {
+                source,
+                result,
+                files,
+            }
+        })
+        .collect()
+}
+
+fn inspect(fs: &dyn FileSystem, path: &Path) -> FileState {
+    if let Ok(target) = fs.read_link(path) {
+        return FileState::Symlink(target);
+    } else if fs.is_dir(path) {
+        return FileState::Directory;
+    }
+    let contents = match fs.read(path) {
+        Ok(contents) => contents,
+        Err(err) if err.kind() == io::ErrorKind::NotFound => return FileState::Missing,
+        Err(err) => return FileState::Error(err),
+    };
+    if contents.starts_with(b"TZif") {
+        return FileState::Tzif(contents.len());
+    }
+    let len = contents.len();
+    match String::from_utf8(contents) {
+        Ok(text) => {
+            let lines = text
+                .lines()
+                .take(MAX_LINES)
+                .map(|line| line.to_owned())
+                .collect();
+            FileState::Text {
+                len,
+                lines,
+                more_lines: text.lines().count().saturating_sub(MAX_LINES),
+            }
+        }
+        Err(_) => FileState::Binary(len),
+    }
+}
+
+/// A file system that remembers the paths that were accessed.
+///
+/// Files below a directory that was listed are not remembered, because the zoneinfo database
+/// has thousands of them.
+struct RecordingFs<'a> {
+    fs: &'a dyn FileSystem,
+    paths: RefCell<Vec<PathBuf>>,
+    listed: RefCell<Vec<PathBuf>>,
+}
+
+impl RecordingFs<'_> {
+    fn record(&self, path: &Path) {
+        let mut paths = self.paths.borrow_mut();
+        let listed = self.listed.borrow();
+        if !listed.iter().any(|dir| path.starts_with(dir)) && !paths.iter().any(|p| p == path) {
+            paths.push(path.to_owned());
+        }
+    }
+}
+
+impl FileSystem for RecordingFs<'_> {
+    fn read(&self, path: &Path) -> io::Result<Vec<u8>> {
+        self.record(path);
+        self.fs.read(path)
+    }
+
+    fn read_link(&self, path: &Path) -> io::Result<PathBuf> {
+        self.record(path);
+        self.fs.read_link(path)
+    }
+
+    fn read_dir(&self, path: &Path) -> io::Result<Vec<(String, bool)>> {
+        self.record(path);
+        self.listed.borrow_mut().push(path.to_owned());
+        self.fs.read_dir(path)
+    }
+
+    fn modified(&self, path: &Path) -> io::Result<SystemTime> {
+        self.fs.modified(path)
+    }
+
+    fn is_dir(&self, path: &Path) -> bool {
+        self.record(path);
+        self.fs.is_dir(path)
+    }
+}
+
+impl fmt::Display for Diagnostics {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "iana-time-zone {} on {} ({})",
+            env!("CARGO_PKG_VERSION"),
+            env::consts::OS,
+            env::consts::ARCH,
+        )?;
+        match &self.result {
+            Ok(detection) => {
+                write!(
+                    f,
+                    "Time zone: {} (from {}",
+                    detection.name, detection.source
+                )?;
+                if let Some(path) = &detection.path {
+                    write!(f, " {}", path.display())?;
+                }
+                writeln!(f, ")")?;
+            }
+            Err(err) => writeln!(f, "Time zone: not found: {}", err)?,
+        }
+        match (&self.root, &self.tz) {
+            (Some(root), _) => writeln!(f, "Root: {}\nTZ: not consulted", root.display())?,
+            (None, Some(tz)) => writeln!(f, "TZ: {:?}", tz)?,
+            (None, None) => writeln!(f, "TZ: not set")?,
+        }
+
+        writeln!(f)?;
+        writeln!(f, "Sources, in order:")?;
+        if self.sources.is_empty() {
+            writeln!(f, "  none, the platform is not supported")?;
+        }
+        for source in &self.sources {
+            write!(f, "  {}: ", source.source.name())?;
+            match &source.result {
+                Ok(detection) => match &detection.posix_tz {
+                    Some(rule) if *rule == detection.name => writeln!(
+                        f,
+                        "found the POSIX TZ rule {:?}, which matches no IANA time zone",
+                        rule
+                    )?,
+                    Some(rule) => writeln!(
+                        f,
+                        "found {} (for the POSIX TZ rule {:?})",
+                        detection.name, rule
+                    )?,
+                    None => writeln!(f, "found {}", detection.name)?,
+                },
+                Err(err @ GetTimezoneError::InvalidTimezone(_)) => {
+                    writeln!(f, "rejected, {}", err)?
+                }
+                Err(err) => writeln!(f, "failed, {}", err)?,
+            }
+            for (path, state) in &source.files {
+                write!(f, "      {}", path.display())?;
+                match state {
+                    FileState::Missing => writeln!(f, ": does not exist")?,
+                    FileState::Symlink(target) => writeln!(f, " -> {}", target.display())?,
+                    FileState::Directory => writeln!(f, ": directory")?,
+                    FileState::Tzif(len) => writeln!(f, ": TZif file, {} bytes", len)?,
+                    FileState::Binary(len) => writeln!(f, ": binary file, {} bytes", len)?,
+                    FileState::Error(err) => writeln!(f, ": {}", err)?,
+                    FileState::Text {
+                        len,
+                        lines,
+                        more_lines,
+                    } => {
+                        writeln!(f, ": {} bytes", len)?;
+                        for line in lines {
+                            let short = line.chars().take(MAX_LINE_LEN).collect::<String>();
+                            let ellipsis = if short.len() < line.len() { "..." } else { "" };
+                            writeln!(f, "        {:?}{}", short, ellipsis)?;
+                        }
+                        if *more_lines > 0 {
+                            writeln!(f, "        ... {} more lines", more_lines)?;
+                        }
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(all(test, unix))]
+mod tests {
+    use std::path::Path;
+
+    use super::{diagnose_sources, Diagnostics, FileState};
+    use crate::filesystem::MemFs;
+    use crate::unix::{ILLUMOS, LINUX};
+    use crate::{BuiltinSource, GetTimezoneError};
+
+    /// The report of `sources` on `fs`, except `TZ`, which is read from the environment of the
+    /// test.
+    fn report(fs: &MemFs, sources: &[BuiltinSource]) -> String {
+        let sources = sources
+            .iter()
+            .copied()
+            .filter(|&source| source != BuiltinSource::TZ_ENV)
+            .collect::<Vec<_>>();
+        let sources = diagnose_sources(fs, Path::new("/"), &sources);
+        let diagnostics = Diagnostics {
+            result: Err(GetTimezoneError::Unsupported),
+            root: None,
+            tz: None,
+            sources,
+        };
+        diagnostics.to_string()
+    }
+
+    #[test]
+    fn test_diagnose() {
+        let mut fs = MemFs::layout("debian");
+        fs.remove(Path::new("/etc/localtime"));
+        fs.write(Path::new("/etc/localtime"), "not a TZif file\n");
+        fs.write(Path::new("/etc/timezone"), "Europe/Berlim\n");
+        fs.write(
+            Path::new("/etc/config/system"),
+            "config system\n\toption zonename 'Europe/Berlin\n",
+        );
+        let text = report(&fs, LINUX);
+        for expected in [
+            "  /etc/localtime: failed, could not read /etc/localtime: ",
+            "      /etc/localtime: 16 bytes\n        \"not a TZif file\"\n",
+            "  /etc/timezone: rejected, not an IANA time zone: \"Europe/Berlim\"\n",
+            "      /usr/share/zoneinfo/Europe/Berlim: does not exist\n",
+            "  /etc/config/system: failed, could not parse the time zone of /etc/config/system: \
+             unterminated quote in line 2\n",
+            "        \"\\toption zonename 'Europe/Berlin\"\n",
+            "  /etc/localtime (copy): failed, ",
+            "      /usr/share/zoneinfo: directory\n",
+        ] {
+            assert!(text.contains(expected), "{:?} not in:\n{}", expected, text);
+        }
+
+        let mut fs = MemFs::layout("solaris");
+        fs.write(
+            Path::new("/etc/default/init"),
+            "# TZ=Asia/Tokyo\nCMASK=022\n",
+        );
+        let text = report(&fs, ILLUMOS);
+        assert!(
+            text.contains(
+                "  /etc/default/init: failed, no time zone is configured in /etc/default/init\n"
+            ),
+            "{}",
+            text,
+        );
+
+        // The files are available without `Display`, too.
+        let sources = diagnose_sources(&fs, Path::new("/"), &[BuiltinSource::ETC_DEFAULT_INIT]);
+        assert!(matches!(
+            &sources[0].files[..],
+            [(path, FileState::Text { lines, more_lines: 0, .. })]
+                if path == Path::new("/etc/default/init") && lines[0] == "# TZ=Asia/Tokyo",
+        ));
+    }
+}
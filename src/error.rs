@@ -0,0 +1,255 @@
+//! The error type of this crate.
+
+use std::fmt;
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// Error types
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum GetTimezoneError {
+    /// The time zone of a source could not be parsed
+    FailedParsingString {
+        /// The source that contained the value.
+        context: Context,
+        /// Why the value could not be parsed, e.g. `"unterminated quote in line 3"`.
+        reason: String,
+    },
+    /// Wrapped IO error
+    IoError {
+        /// The file that could not be read.
+        context: Context,
+        /// The underlying error.
+        source: io::Error,
+    },
+    /// Platform-specific error from the operating system
+    OsError {
+        /// The API that failed.
+        context: Context,
+        /// The status code of the API, if it returned one.
+        code: Option<OsCode>,
+    },
+    /// A source contained a name that is not an IANA time zone
+    InvalidTimezone(String),
+    /// A source contained a POSIX TZ rule string instead of an IANA time zone, see
+    /// [`Detection::posix_tz`](crate::Detection::posix_tz)
+    PosixRule(String),
+    /// A source exists, but it does not configure a time zone, e.g. `TZ` is unset
+    NotConfigured {
+        /// The source that was consulted.
+        context: Context,
+    },
+    /// This platform is not supported, see the feature `fallback`
+    Unsupported,
+}
+
+/// The source, file or API that an error is about.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Context {
+    /// A file, or a symlink.
+    Path(PathBuf),
+    /// An environment variable.
+    EnvVar(&'static str),
+    /// An API of the operating system or the runtime environment.
+    Api(&'static str),
+    /// A value that was passed to a function of this crate.
+    Value(String),
+    /// The error was converted from an [`io::Error`] without recording which file it is about.
+    Unknown,
+}
+
+/// The status code of an API of the operating system.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum OsCode {
+    /// A Windows `HRESULT`.
+    HResult(i32),
+    /// An OpenHarmony `TimeService_ErrCode`.
+    TimeService(u32),
+    /// A Unix `errno`.
+    Errno(i32),
+}
+
+// Not every backend uses every constructor.
+#[allow(dead_code)]
+impl GetTimezoneError {
+    /// Wrap an error of reading the file `path`, e.g. `.map_err(GetTimezoneError::io(path))`.
+    pub(crate) fn io(path: &Path) -> impl FnOnce(io::Error) -> Self + '_ {
+        move |source| GetTimezoneError::IoError {
+            context: Context::Path(path.to_owned()),
+            source,
+        }
+    }
+
+    /// The contents of the file `path` could not be parsed, because of `reason`.
+    pub(crate) fn parse(path: &Path, reason: impl Into<String>) -> Self {
+        GetTimezoneError::FailedParsingString {
+            context: Context::Path(path.to_owned()),
+            reason: reason.into(),
+        }
+    }
+
+    /// The API `name` failed.
+    pub(crate) fn os(name: &'static str, code: Option<OsCode>) -> Self {
+        GetTimezoneError::OsError {
+            context: Context::Api(name),
+            code,
+        }
+    }
+
+    /// The system call `name` failed, and set `errno`.
+    pub(crate) fn last_os_error(name: &'static str) -> Self {
+        let code = io::Error::last_os_error().raw_os_error().map(OsCode::Errno);
+        Self::os(name, code)
+    }
+}
+
+impl GetTimezoneError {
+    /// Check if the error means that no time zone is configured, instead of a broken
+    /// configuration.
+    ///
+    /// This is the case for [`GetTimezoneError::NotConfigured`], and for a
+    /// [`GetTimezoneError::IoError`] about a file that does not exist.
+    pub fn is_not_configured(&self) -> bool {
+        match self {
+            GetTimezoneError::NotConfigured { .. } => true,
+            GetTimezoneError::IoError { source, .. } => source.kind() == io::ErrorKind::NotFound,
+            _ => false,
+        }
+    }
+}
+
+impl std::error::Error for GetTimezoneError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            GetTimezoneError::IoError { source, .. } => Some(source),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for GetTimezoneError {
+    fn from(source: io::Error) -> Self {
+        GetTimezoneError::IoError {
+            context: Context::Unknown,
+            source,
+        }
+    }
+}
+
+impl fmt::Display for GetTimezoneError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            GetTimezoneError::FailedParsingString { context, reason } => {
+                write!(
+                    f,
+                    "could not parse the time zone of {}: {}",
+                    context, reason
+                )
+            }
+            GetTimezoneError::IoError { context, source } => {
+                write!(f, "could not read {}: {}", context, source)
+            }
+            GetTimezoneError::OsError {
+                context,
+                code: None,
+            } => write!(f, "{} failed", context),
+            GetTimezoneError::OsError {
+                context,
+                code: Some(code),
+            } => write!(f, "{} failed with {}", context, code),
+            GetTimezoneError::InvalidTimezone(name) => {
+                write!(f, "not an IANA time zone: {:?}", name)
+            }
+            GetTimezoneError::PosixRule(rule) => {
+                write!(
+                    f,
+                    "a POSIX TZ rule instead of an IANA time zone: {:?}",
+                    rule
+                )
+            }
+            GetTimezoneError::NotConfigured { context } => {
+                write!(f, "no time zone is configured in {}", context)
+            }
+            GetTimezoneError::Unsupported => {
+                f.write_str("detecting the time zone is not supported on this platform")
+            }
+        }
+    }
+}
+
+impl fmt::Display for Context {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Context::Path(path) => write!(f, "{}", path.display()),
+            Context::EnvVar(name) => write!(f, "the environment variable {}", name),
+            Context::Api(name) => f.write_str(name),
+            Context::Value(value) => write!(f, "{:?}", value),
+            Context::Unknown => f.write_str("an unknown file"),
+        }
+    }
+}
+
+impl fmt::Display for OsCode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            OsCode::HResult(code) => write!(f, "HRESULT {:#010X}", code),
+            OsCode::TimeService(code) => write!(f, "TimeService_ErrCode {}", code),
+            OsCode::Errno(code) => write!(f, "{}", io::Error::from_raw_os_error(code)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io;
+    use std::path::Path;
+
+    use super::{Context, GetTimezoneError, OsCode};
+
+    #[test]
+    fn test_display() {
+        let err = GetTimezoneError::io(Path::new("/etc/timezone"))(io::Error::new(
+            io::ErrorKind::NotFound,
+            "not found",
+        ));
+        assert_eq!(err.to_string(), "could not read /etc/timezone: not found");
+        assert_eq!(
+            GetTimezoneError::parse(
+                Path::new("/etc/config/system"),
+                "unterminated quote in line 3"
+            )
+            .to_string(),
+            "could not parse the time zone of /etc/config/system: unterminated quote in line 3",
+        );
+        assert_eq!(
+            GetTimezoneError::os("CFTimeZoneCopySystem()", None).to_string(),
+            "CFTimeZoneCopySystem() failed",
+        );
+        assert_eq!(
+            GetTimezoneError::os(
+                "Calendar::new()",
+                Some(OsCode::HResult(0x8000_4005_u32 as i32))
+            )
+            .to_string(),
+            "Calendar::new() failed with HRESULT 0x80004005",
+        );
+        assert_eq!(
+            GetTimezoneError::os("inotify_init1()", Some(OsCode::Errno(24))).to_string(),
+            format!(
+                "inotify_init1() failed with {}",
+                io::Error::from_raw_os_error(24)
+            ),
+        );
+        let err = GetTimezoneError::from(io::Error::new(io::ErrorKind::NotFound, "not found"));
+        assert_eq!(err.to_string(), "could not read an unknown file: not found");
+        let err = GetTimezoneError::NotConfigured {
+            context: Context::EnvVar("TZ"),
+        };
+        assert_eq!(
+            err.to_string(),
+            "no time zone is configured in the environment variable TZ"
+        );
+    }
+}
@@ -0,0 +1,253 @@
+//! The file system that the sources of the time zone read.
+//!
+//! The sources are written against the [`FileSystem`] trait instead of `std::fs`, so the tests
+//! can run them against the layouts of many operating systems in memory.
+
+use std::io;
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
+
+/// The operations on a file system that the sources need.
+///
+/// All paths are absolute.
+pub(crate) trait FileSystem {
+    /// Read the whole file `path`, following symlinks.
+    fn read(&self, path: &Path) -> io::Result<Vec<u8>>;
+
+    /// Read the target of the symlink `path`.
+    fn read_link(&self, path: &Path) -> io::Result<PathBuf>;
+
+    /// List the directory `path` as the names of its entries, and whether they are directories.
+    ///
+    /// Symlinks in the directory are not followed, so they are never directories. Names that are
+    /// not valid UTF-8 are skipped.
+    fn read_dir(&self, path: &Path) -> io::Result<Vec<(String, bool)>>;
+
+    /// The modification time of `path`, following symlinks.
+    fn modified(&self, path: &Path) -> io::Result<SystemTime>;
+
+    /// Check if `path` is a directory, following symlinks.
+    fn is_dir(&self, path: &Path) -> bool;
+
+    /// Read the whole file `path` as UTF-8.
+    fn read_to_string(&self, path: &Path) -> io::Result<String> {
+        String::from_utf8(self.read(path)?)
+            .map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))
+    }
+}
+
+/// The real file system of the host.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct StdFs;
+
+impl FileSystem for StdFs {
+    fn read(&self, path: &Path) -> io::Result<Vec<u8>> {
+        std::fs::read(path)
+    }
+
+    fn read_link(&self, path: &Path) -> io::Result<PathBuf> {
+        std::fs::read_link(path)
+    }
+
+    fn read_dir(&self, path: &Path) -> io::Result<Vec<(String, bool)>> {
+        let mut entries = Vec::new();
+        for entry in std::fs::read_dir(path)? {
+            let entry = entry?;
+            if let Ok(name) = entry.file_name().into_string() {
+                entries.push((name, entry.file_type()?.is_dir()));
+            }
+        }
+        Ok(entries)
+    }
+
+    fn modified(&self, path: &Path) -> io::Result<SystemTime> {
+        std::fs::metadata(path)?.modified()
+    }
+
+    fn is_dir(&self, path: &Path) -> bool {
+        path.is_dir()
+    }
+}
+
+#[cfg(all(test, unix))]
+pub(crate) use self::mem::MemFs;
+
+#[cfg(all(test, unix))]
+mod mem {
+    use std::collections::BTreeMap;
+    use std::io;
+    use std::path::{Component, Path, PathBuf};
+    use std::sync::atomic::{AtomicU64, Ordering};
+    use std::time::{Duration, SystemTime};
+
+    use super::FileSystem;
+
+    #[derive(Debug, Clone)]
+    enum Node {
+        File(Vec<u8>),
+        Symlink(PathBuf),
+        Dir,
+    }
+
+    /// A file system in memory, which is loaded from a layout in `tests/fixtures/layouts/`.
+    ///
+    /// A layout has one entry per line:
+    ///
+    /// - `link <path> <target>` creates a symlink.
+    /// - `tzif <path> <name>` copies the file `tests/fixtures/tzif/<name>`.
+    /// - `file <path>` creates a file with the following lines that are indented with a tab.
+    /// - `dir <path>` creates an empty directory.
+    ///
+    /// Empty lines, and lines starting with `#`, are ignored.
+    #[derive(Debug)]
+    pub(crate) struct MemFs {
+        nodes: BTreeMap<PathBuf, Node>,
+        /// The modification time of all files, which is different for every instance, so the
+        /// caches of the zoneinfo databases are not shared between them.
+        modified: SystemTime,
+    }
+
+    impl MemFs {
+        /// Load the layout `tests/fixtures/layouts/<name>`.
+        pub(crate) fn layout(name: &str) -> Self {
+            static GENERATION: AtomicU64 = AtomicU64::new(1);
+
+            let fixtures = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures");
+            let layout = std::fs::read_to_string(fixtures.join("layouts").join(name)).unwrap();
+            let mut fs = Self {
+                nodes: BTreeMap::new(),
+                modified: SystemTime::UNIX_EPOCH
+                    + Duration::from_secs(GENERATION.fetch_add(1, Ordering::Relaxed)),
+            };
+            fs.insert(PathBuf::from("/"), Node::Dir);
+
+            let mut file: Option<(PathBuf, String)> = None;
+            for line in layout.lines() {
+                if let Some(content) = line.strip_prefix('\t') {
+                    let (_, contents) = file.as_mut().unwrap();
+                    contents.push_str(content);
+                    contents.push('\n');
+                    continue;
+                }
+                if let Some((path, contents)) = file.take() {
+                    fs.insert(path, Node::File(contents.into_bytes()));
+                }
+                let words = line.split_whitespace().collect::<Vec<_>>();
+                let path = |idx: usize| PathBuf::from(words[idx]);
+                match words[..] {
+                    [] => {}
+                    [comment, ..] if comment.starts_with('#') => {}
+                    ["link", _, target] => fs.insert(path(1), Node::Symlink(target.into())),
+                    ["tzif", _, name] => {
+                        let contents = std::fs::read(fixtures.join("tzif").join(name)).unwrap();
+                        fs.insert(path(1), Node::File(contents));
+                    }
+                    ["file", _] => file = Some((path(1), String::new())),
+                    ["dir", _] => fs.insert(path(1), Node::Dir),
+                    _ => panic!("invalid line in the layout {}: {:?}", name, line),
+                }
+            }
+            if let Some((path, contents)) = file.take() {
+                fs.insert(path, Node::File(contents.into_bytes()));
+            }
+            fs
+        }
+
+        /// Create or replace the file `path`.
+        pub(crate) fn write(&mut self, path: &Path, contents: impl Into<Vec<u8>>) {
+            self.insert(path.to_owned(), Node::File(contents.into()));
+        }
+
+        /// Remove the file, symlink or directory `path`, and everything below it.
+        pub(crate) fn remove(&mut self, path: &Path) {
+            self.nodes.retain(|node, _| !node.starts_with(path));
+        }
+
+        /// Insert `node`, and create its parent directories.
+        fn insert(&mut self, path: PathBuf, node: Node) {
+            for parent in path.ancestors().skip(1) {
+                self.nodes.entry(parent.to_owned()).or_insert(Node::Dir);
+            }
+            self.nodes.insert(path, node);
+        }
+
+        /// Resolve the symlinks in `path`, except in its last component if `follow` is false.
+        fn resolve(&self, path: &Path, follow: bool) -> io::Result<(PathBuf, &Node)> {
+            let mut resolved = PathBuf::from("/");
+            let mut components = path.components().collect::<Vec<_>>();
+            components.reverse();
+            let mut hops = 0;
+            while let Some(component) = components.pop() {
+                match component {
+                    Component::Normal(name) => resolved.push(name),
+                    Component::ParentDir => {
+                        resolved.pop();
+                        continue;
+                    }
+                    _ => continue,
+                }
+                let node = self.nodes.get(&resolved).ok_or_else(not_found)?;
+                if let Node::Symlink(target) = node {
+                    if components.is_empty() && !follow {
+                        break;
+                    }
+                    hops += 1;
+                    if hops > 40 {
+                        return Err(io::Error::new(io::ErrorKind::Other, "symlink loop"));
+                    }
+                    resolved.pop();
+                    if target.is_absolute() {
+                        resolved = PathBuf::from("/");
+                    }
+                    components.extend(target.components().rev());
+                }
+            }
+            let node = self.nodes.get(&resolved).ok_or_else(not_found)?;
+            Ok((resolved, node))
+        }
+    }
+
+    fn not_found() -> io::Error {
+        io::Error::new(io::ErrorKind::NotFound, "not found")
+    }
+
+    impl FileSystem for MemFs {
+        fn read(&self, path: &Path) -> io::Result<Vec<u8>> {
+            match self.resolve(path, true)?.1 {
+                Node::File(contents) => Ok(contents.clone()),
+                _ => Err(io::Error::new(io::ErrorKind::Other, "is a directory")),
+            }
+        }
+
+        fn read_link(&self, path: &Path) -> io::Result<PathBuf> {
+            match self.resolve(path, false)?.1 {
+                Node::Symlink(target) => Ok(target.clone()),
+                _ => Err(io::Error::new(io::ErrorKind::InvalidInput, "not a symlink")),
+            }
+        }
+
+        fn read_dir(&self, path: &Path) -> io::Result<Vec<(String, bool)>> {
+            let (dir, node) = self.resolve(path, true)?;
+            if !matches!(node, Node::Dir) {
+                return Err(io::Error::new(io::ErrorKind::Other, "not a directory"));
+            }
+            Ok(self
+                .nodes
+                .iter()
+                .filter(|(path, _)| path.parent() == Some(&dir))
+                .filter_map(|(path, node)| {
+                    let name = path.file_name()?.to_str()?.to_owned();
+                    Some((name, matches!(node, Node::Dir)))
+                })
+                .collect())
+        }
+
+        fn modified(&self, path: &Path) -> io::Result<SystemTime> {
+            self.resolve(path, true).map(|_| self.modified)
+        }
+
+        fn is_dir(&self, path: &Path) -> bool {
+            matches!(self.resolve(path, true), Ok((_, Node::Dir)))
+        }
+    }
+}
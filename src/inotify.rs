@@ -0,0 +1,205 @@
+//! The inotify instance behind `watch()` and `timezone_changes()` on Linux.
+
+use std::ffi::{CString, OsStr, OsString};
+use std::mem::size_of;
+use std::os::raw::c_int;
+use std::os::unix::ffi::OsStrExt;
+use std::os::unix::io::{AsRawFd, RawFd};
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use crate::cached::WATCHED;
+use crate::filesystem::StdFs;
+use crate::unix::{in_root, symlink_chain};
+use crate::{Detection, GetTimezoneError};
+
+/// How long to wait for more events after a change, e.g. the rename that follows the creation
+/// of a temporary file.
+pub(crate) const DEBOUNCE: Duration = Duration::from_millis(100);
+
+const MASK: u32 = libc::IN_CREATE
+    | libc::IN_DELETE
+    | libc::IN_MOVED_FROM
+    | libc::IN_MOVED_TO
+    | libc::IN_CLOSE_WRITE
+    | libc::IN_ATTRIB;
+
+/// A change of the time zone, as reported by [`watch()`](crate::watch) and
+/// [`timezone_changes()`](crate::timezone_changes).
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct TimezoneChange {
+    /// The previous time zone, or `None` if no time zone could be detected before.
+    pub old: Option<String>,
+    /// The new time zone.
+    pub new: String,
+}
+
+/// A non-blocking inotify instance that watches the files the time zone is read from.
+#[derive(Debug)]
+pub(crate) struct Inotify {
+    fd: Fd,
+    root: PathBuf,
+    detect: fn(&Path) -> Result<Detection, GetTimezoneError>,
+    /// The watched directories, and the names of the relevant files in them.
+    watches: Vec<(c_int, OsString)>,
+    current: Option<String>,
+}
+
+impl Inotify {
+    pub(crate) fn new(
+        root: PathBuf,
+        detect: fn(&Path) -> Result<Detection, GetTimezoneError>,
+    ) -> Result<Self, GetTimezoneError> {
+        // SAFETY: The function has no preconditions, and the result is checked.
+        let fd = Fd::new(
+            unsafe { libc::inotify_init1(libc::IN_CLOEXEC | libc::IN_NONBLOCK) },
+            "inotify_init1()",
+        )?;
+        let mut inotify = Self {
+            fd,
+            root,
+            detect,
+            watches: Vec::new(),
+            current: None,
+        };
+        // The files are watched before the first detection, so no change can be missed.
+        inotify.add_watches();
+        inotify.current = (inotify.detect)(&inotify.root).ok().map(|d| d.name);
+        Ok(inotify)
+    }
+
+    /// Detect the time zone again after the events settled, and return it if it changed.
+    #[cfg(feature = "watch")]
+    pub(crate) fn detect_change(&mut self) -> Option<TimezoneChange> {
+        let detect = self.detector();
+        self.update(detect())
+    }
+
+    /// Return the detection to run after the events settled, so it can be run on another
+    /// thread.
+    pub(crate) fn detector(&mut self) -> impl FnOnce() -> Option<String> + Send + 'static {
+        // A new symlink may point to different directories.
+        self.add_watches();
+        let (root, detect) = (self.root.clone(), self.detect);
+        // The configuration may be incomplete while it is changed. The next event tells when
+        // it is complete.
+        move || detect(&root).ok().map(|d| d.name)
+    }
+
+    /// Remember the detected time zone `new`, and return the change if it differs.
+    pub(crate) fn update(&mut self, new: Option<String>) -> Option<TimezoneChange> {
+        let new = new?;
+        if self.current.as_ref() == Some(&new) {
+            return None;
+        }
+        let old = self.current.replace(new.clone());
+        Some(TimezoneChange { old, new })
+    }
+
+    /// Watch the directories of the watched files, and of the symlinks `/etc/localtime` points
+    /// to, for changes of these files.
+    fn add_watches(&mut self) {
+        let mut paths: Vec<PathBuf> = WATCHED
+            .unwrap_or_default()
+            .iter()
+            .map(PathBuf::from)
+            .collect();
+        if let Ok(chain) = symlink_chain(&StdFs, &self.root, Path::new("/etc/localtime")) {
+            paths.extend(chain);
+        }
+
+        for path in paths {
+            let path = in_root(&self.root, path);
+            let (dir, name) = match (path.parent(), path.file_name()) {
+                (Some(dir), Some(name)) => (dir, name),
+                _ => continue,
+            };
+            let dir = match CString::new(dir.as_os_str().as_bytes()) {
+                Ok(dir) => dir,
+                Err(_) => continue,
+            };
+            // Watching a directory twice returns the same watch descriptor. Missing directories
+            // are skipped.
+            // SAFETY: Both file descriptor and string are valid.
+            let wd = unsafe { libc::inotify_add_watch(self.fd.0, dir.as_ptr(), MASK) };
+            if wd >= 0 && !self.is_watched(wd, name) {
+                self.watches.push((wd, name.to_owned()));
+            }
+        }
+    }
+
+    fn is_watched(&self, wd: c_int, name: &OsStr) -> bool {
+        self.watches.iter().any(|(w, n)| *w == wd && n == name)
+    }
+
+    /// Read all pending events, and return if any of them is about a watched file.
+    pub(crate) fn read_events(&mut self) -> bool {
+        const HEADER: usize = size_of::<libc::inotify_event>();
+
+        let mut relevant = false;
+        let mut buf = [0_u8; 4096];
+        loop {
+            // SAFETY: The buffer is valid for its length.
+            let len = unsafe { libc::read(self.fd.0, buf.as_mut_ptr().cast(), buf.len()) };
+            if len <= 0 {
+                return relevant;
+            }
+            let events = &buf[..len as usize];
+            // The buffer is not aligned for `inotify_event`, so its fields are read as bytes.
+            let mut offset = 0;
+            while offset + HEADER <= events.len() {
+                let field = |index: usize| {
+                    let start = offset + 4 * index;
+                    let mut bytes = [0; 4];
+                    bytes.copy_from_slice(&events[start..start + 4]);
+                    bytes
+                };
+                let wd = c_int::from_ne_bytes(field(0));
+                let mask = u32::from_ne_bytes(field(1));
+                let name_len = u32::from_ne_bytes(field(3)) as usize;
+                let name = &events[offset + HEADER..offset + HEADER + name_len];
+                // The name is padded with NUL bytes.
+                let name = name.split(|&b| b == 0).next().unwrap_or_default();
+                offset += HEADER + name_len;
+
+                if mask & libc::IN_Q_OVERFLOW != 0 || self.is_watched(wd, OsStr::from_bytes(name)) {
+                    relevant = true;
+                }
+            }
+        }
+    }
+}
+
+impl AsRawFd for Inotify {
+    fn as_raw_fd(&self) -> RawFd {
+        self.fd.0
+    }
+}
+
+/// An owned file descriptor. `std::os::fd::OwnedFd` is not available in our MSRV.
+#[derive(Debug)]
+pub(crate) struct Fd(pub(crate) c_int);
+
+impl Fd {
+    pub(crate) fn new(fd: c_int, name: &'static str) -> Result<Self, GetTimezoneError> {
+        if fd >= 0 {
+            Ok(Self(fd))
+        } else {
+            Err(GetTimezoneError::last_os_error(name))
+        }
+    }
+}
+
+impl AsRawFd for Fd {
+    fn as_raw_fd(&self) -> RawFd {
+        self.0
+    }
+}
+
+impl Drop for Fd {
+    fn drop(&mut self) {
+        // SAFETY: The file descriptor is owned, and not used after this.
+        unsafe { libc::close(self.0) };
+    }
+}
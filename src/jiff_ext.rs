@@ -0,0 +1,64 @@
+//! Integration with the crate `jiff`.
+
+use jiff::tz::TimeZone;
+
+use crate::{Detection, GetTimezoneError};
+
+/// Get the current time zone as a [`jiff::tz::TimeZone`].
+///
+/// The returned time zone carries the detected IANA name, see [`TimeZone::iana_name()`]. On
+/// Linux, the BSDs, illumos and macOS, its rules are read from the TZif file that was found by
+/// the detection, e.g. the target of `/etc/localtime`, so the rules are consistent with the name,
+/// and with the C library. On other platforms, the time zone database of `jiff` is used. If a
+/// POSIX TZ rule string is configured, then the time zone follows the rule, and has no name.
+///
+/// The feature `jiff` must be enabled.
+///
+/// ```rust
+/// let tz = iana_time_zone::get_jiff_timezone()?;
+/// println!("The current time zone is: {}", tz.iana_name().unwrap_or_default());
+/// # Ok::<(), iana_time_zone::GetTimezoneError>(())
+/// ```
+pub fn get_jiff_timezone() -> Result<TimeZone, GetTimezoneError> {
+    let detection = crate::detect()?;
+    // The C library uses the rule, not the zone that was inferred from it.
+    if let Some(rule) = detection.posix_tz {
+        return TimeZone::posix(&rule).map_err(|_| GetTimezoneError::PosixRule(rule));
+    }
+    to_jiff_timezone(detection)
+}
+
+#[cfg(unix)]
+fn to_jiff_timezone(detection: Detection) -> Result<TimeZone, GetTimezoneError> {
+    use std::fs;
+    use std::path::Path;
+
+    match crate::unix::tzif_path(&crate::filesystem::StdFs, Path::new("/"), &detection) {
+        Some(path) => {
+            let data = fs::read(&path).map_err(GetTimezoneError::io(&path))?;
+            TimeZone::tzif(&detection.name, &data)
+                .map_err(|_| GetTimezoneError::parse(&path, "invalid TZif file"))
+        }
+        None => from_jiff_db(detection),
+    }
+}
+
+#[cfg(not(unix))]
+fn to_jiff_timezone(detection: Detection) -> Result<TimeZone, GetTimezoneError> {
+    from_jiff_db(detection)
+}
+
+fn from_jiff_db(detection: Detection) -> Result<TimeZone, GetTimezoneError> {
+    TimeZone::get(&detection.name).map_err(|_| GetTimezoneError::InvalidTimezone(detection.name))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::get_jiff_timezone;
+
+    #[test]
+    fn test_get_jiff_timezone() {
+        let tz = get_jiff_timezone().unwrap();
+        assert_eq!(tz.iana_name(), Some(&*crate::get_timezone().unwrap()));
+    }
+}
@@ -0,0 +1,41 @@
+//! A minimal lazily initialized static value.
+//!
+//! `std::sync::OnceLock` and `std::sync::LazyLock` are not available in our MSRV, and neither is
+//! a `const fn Mutex::new()`.
+
+use std::cell::UnsafeCell;
+use std::sync::Once;
+
+/// A value that is initialized on first access.
+pub(crate) struct Lazy<T> {
+    once: Once,
+    value: UnsafeCell<Option<T>>,
+    init: fn() -> T,
+}
+
+// SAFETY: The value is only written once, synchronized by `once`, and only shared afterwards.
+unsafe impl<T: Send + Sync> Sync for Lazy<T> {}
+
+impl<T> Lazy<T> {
+    pub(crate) const fn new(init: fn() -> T) -> Self {
+        Self {
+            once: Once::new(),
+            value: UnsafeCell::new(None),
+            init,
+        }
+    }
+
+    pub(crate) fn get(&self) -> &T {
+        self.once.call_once(|| {
+            let value = (self.init)();
+            // SAFETY: `once` is synchronizing. The value is only assigned to once, and no
+            // reference to it was handed out yet.
+            unsafe { *self.value.get() = Some(value) };
+        });
+        // SAFETY: `once` is synchronizing. The value was assigned to, and is never changed again.
+        match unsafe { &*self.value.get() } {
+            Some(value) => value,
+            None => unreachable!(),
+        }
+    }
+}
@@ -30,9 +30,47 @@
 //! let tz: chrono_tz::Tz = tz_str.parse()?;
 //! # Ok::<(), Box<dyn std::error::Error>>(())
 //! ```
+//!
+//! With the feature `chrono-tz`, `get_chrono_tz()` does this for you, and also tries the
+//! backward compatible aliases of the name if `chrono-tz` does not know it.
 
+#[cfg(feature = "tokio")]
+mod async_api;
+mod cached;
+#[cfg(feature = "chrono-tz")]
+mod chrono_tz_ext;
+mod detection;
+mod detector;
+mod diagnose;
+mod error;
 #[allow(dead_code)]
 mod ffi_utils;
+#[cfg_attr(not(unix), allow(dead_code))]
+mod filesystem;
+#[cfg(all(
+    any(feature = "watch", feature = "tokio"),
+    target_os = "linux",
+    not(target_env = "ohos")
+))]
+mod inotify;
+#[cfg(feature = "jiff")]
+mod jiff_ext;
+mod lazy;
+mod local_zone;
+mod posix_tz;
+#[cfg(feature = "testing")]
+mod testing;
+mod tzdata;
+mod tzdb;
+mod tzif;
+#[cfg(unix)]
+#[allow(dead_code)]
+mod unix;
+#[cfg(all(feature = "watch", target_os = "linux", not(target_env = "ohos")))]
+mod watch;
+mod windows_zones;
+#[cfg(unix)]
+mod zoneinfo;
 
 #[cfg_attr(
     any(all(target_os = "linux", not(target_env = "ohos")), target_os = "hurd"),
@@ -70,50 +108,104 @@ mod ffi_utils;
 )]
 mod platform;
 
-/// Error types
-#[derive(Debug)]
-pub enum GetTimezoneError {
-    /// Failed to parse
-    FailedParsingString,
-    /// Wrapped IO error
-    IoError(std::io::Error),
-    /// Platform-specific error from the operating system
-    OsError,
-}
+#[cfg(unix)]
+use std::path::Path;
 
-impl std::error::Error for GetTimezoneError {
-    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
-        match self {
-            GetTimezoneError::FailedParsingString => None,
-            GetTimezoneError::IoError(err) => Some(err),
-            GetTimezoneError::OsError => None,
-        }
-    }
+#[cfg(feature = "tokio")]
+pub use async_api::get_timezone_async;
+#[cfg(all(feature = "tokio", target_os = "linux", not(target_env = "ohos")))]
+pub use async_api::{timezone_changes, timezone_changes_in_root, TimezoneChanges};
+pub use cached::{get_timezone_cached, CachedTimezone};
+#[cfg(feature = "chrono-tz")]
+pub use chrono_tz_ext::get_chrono_tz;
+pub use detection::{Detection, Source};
+pub use detector::{BuiltinSource, Detector, TimezoneSource};
+#[cfg(unix)]
+pub use diagnose::diagnose_in_root;
+pub use diagnose::{diagnose, Diagnostics, FileState, SourceDiagnostics};
+pub use error::{Context, GetTimezoneError, OsCode};
+#[cfg(all(
+    any(feature = "watch", feature = "tokio"),
+    target_os = "linux",
+    not(target_env = "ohos")
+))]
+pub use inotify::TimezoneChange;
+#[cfg(feature = "jiff")]
+pub use jiff_ext::get_jiff_timezone;
+pub use local_zone::{LocalZone, ZoneTransition};
+pub use posix_tz::{posix_tz_for, PosixTz};
+#[cfg(feature = "testing")]
+pub use testing::{
+    override_timezone, override_timezone_local, set_override, LocalOverrideGuard, OverrideGuard,
+};
+pub use tzdb::{aliases, canonicalize, iana_to_windows, is_valid_timezone, windows_to_iana};
+#[cfg(unix)]
+pub use unix::register_zoneinfo_prefix;
+#[cfg(all(feature = "watch", target_os = "linux", not(target_env = "ohos")))]
+pub use watch::{watch, watch_in_root, Watcher};
+
+/// Get the current IANA time zone as a string.
+///
+/// See the module-level documentation for a usage example and more details
+/// about this function.
+///
+/// Some systems only configure a POSIX TZ rule string, e.g. `TZ=CET-1CEST,M3.5.0,M10.5.0/3`, or
+/// the OpenWrt option `timezone`. Such a rule is not an IANA time zone, so this function fails
+/// with [`GetTimezoneError::PosixRule`] then. [`detect()`] resolves the rule to one
+/// representative IANA time zone with the same current rules, see
+/// [`PosixTz::iana_candidates()`], which is not necessarily the zone of the user, e.g.
+/// `"Europe/Brussels"` instead of `"Europe/Berlin"`, and reports the rule in
+/// [`Detection::posix_tz`].
+#[inline]
+pub fn get_timezone() -> Result<String, GetTimezoneError> {
+    detect().and_then(Detection::into_name)
 }
 
-impl std::fmt::Display for GetTimezoneError {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
-        f.write_str(match self {
-            GetTimezoneError::FailedParsingString => "GetTimezoneError::FailedParsingString",
-            GetTimezoneError::IoError(err) => return err.fmt(f),
-            GetTimezoneError::OsError => "OsError",
-        })
+/// Detect the current IANA time zone, and report where it was found.
+///
+/// This is the same as [`get_timezone()`], but the returned [`Detection`] also tells which
+/// [`Source`] the time zone was read from, the path of the file that was read, and why the
+/// sources that were tried before it failed.
+///
+/// ```rust
+/// let detection = iana_time_zone::detect()?;
+/// println!("{} (from {})", detection.name, detection.source);
+/// for (source, err) in &detection.errors {
+///     println!("  {} failed: {}", source, err);
+/// }
+/// # Ok::<(), iana_time_zone::GetTimezoneError>(())
+/// ```
+pub fn detect() -> Result<Detection, GetTimezoneError> {
+    #[cfg(feature = "testing")]
+    if let Some(detection) = testing::overridden() {
+        return Ok(detection);
     }
+    platform::detect_inner()
 }
 
-impl From<std::io::Error> for GetTimezoneError {
-    fn from(orig: std::io::Error) -> Self {
-        GetTimezoneError::IoError(orig)
-    }
+/// Get the IANA time zone configured in an alternate root file system.
+///
+/// This inspects the configuration files of Linux, the BSDs and illumos below `root`, e.g. in a
+/// mounted container image or a sysroot, instead of the running system. Absolute symlinks are
+/// interpreted relative to `root`, never relative to the host. The `TZ` environment variable
+/// of the current process is not consulted.
+///
+/// ```rust,no_run
+/// let tz_str = iana_time_zone::get_timezone_in_root("/mnt/rootfs".as_ref())?;
+/// println!("The image is configured for: {}", tz_str);
+/// # Ok::<(), iana_time_zone::GetTimezoneError>(())
+/// ```
+#[cfg(unix)]
+pub fn get_timezone_in_root(root: &Path) -> Result<String, GetTimezoneError> {
+    detect_in_root(root).and_then(Detection::into_name)
 }
 
-/// Get the current IANA time zone as a string.
+/// Detect the IANA time zone configured in an alternate root file system.
 ///
-/// See the module-level documentation for a usage example and more details
-/// about this function.
-#[inline]
-pub fn get_timezone() -> Result<String, GetTimezoneError> {
-    platform::get_timezone_inner()
+/// See [`get_timezone_in_root()`] and [`detect()`].
+#[cfg(unix)]
+pub fn detect_in_root(root: &Path) -> Result<Detection, GetTimezoneError> {
+    unix::detect_in_root(root)
 }
 
 #[cfg(test)]
@@ -124,4 +216,11 @@ mod tests {
     fn get_current() {
         println!("current: {}", get_timezone().unwrap());
     }
+
+    #[test]
+    fn detect_current() {
+        let detection = detect().unwrap();
+        assert_eq!(detection.name, get_timezone().unwrap());
+        println!("current: {:?}", detection);
+    }
 }
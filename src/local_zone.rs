@@ -0,0 +1,288 @@
+//! The rules of the local time zone.
+
+use crate::posix_tz::LocalType;
+use crate::tzif::Tzif;
+use crate::{posix_tz_for, Context, Detection, GetTimezoneError, PosixTz};
+
+/// The rules of a time zone, to get its UTC offset, abbreviation and daylight saving time at
+/// any point in time.
+///
+/// [`LocalZone::load()`] reads the TZif file of the current time zone that the detection found,
+/// e.g. the target of `/etc/localtime`. On platforms without a zoneinfo database, e.g. Windows,
+/// only the current rules of the time zone are known, which are embedded in this crate.
+///
+/// ```rust
+/// use iana_time_zone::LocalZone;
+///
+/// let zone = LocalZone::load()?;
+/// let now = std::time::SystemTime::now()
+///     .duration_since(std::time::UNIX_EPOCH)
+///     .unwrap()
+///     .as_secs() as i64;
+/// println!(
+///     "{} is {} seconds east of UTC ({})",
+///     zone.name(),
+///     zone.offset_at(now),
+///     zone.abbreviation_at(now),
+/// );
+/// # Ok::<(), iana_time_zone::GetTimezoneError>(())
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LocalZone {
+    name: String,
+    tzif: Tzif,
+}
+
+impl LocalZone {
+    /// Load the rules of the current time zone.
+    pub fn load() -> Result<Self, GetTimezoneError> {
+        Self::from_detection(crate::detect()?)
+    }
+
+    #[cfg(unix)]
+    fn from_detection(detection: Detection) -> Result<Self, GetTimezoneError> {
+        use std::fs;
+        use std::path::Path;
+
+        match crate::unix::tzif_path(&crate::filesystem::StdFs, Path::new("/"), &detection) {
+            Some(path) => {
+                let data = fs::read(&path).map_err(GetTimezoneError::io(&path))?;
+                let tzif = Tzif::parse(&data)
+                    .ok_or_else(|| GetTimezoneError::parse(&path, "invalid TZif file"))?;
+                Ok(Self {
+                    name: detection.name,
+                    tzif,
+                })
+            }
+            None => Self::embedded(detection),
+        }
+    }
+
+    #[cfg(not(unix))]
+    fn from_detection(detection: Detection) -> Result<Self, GetTimezoneError> {
+        Self::embedded(detection)
+    }
+
+    /// Use the embedded current rules of the time zone, or the rule that it was inferred from.
+    fn embedded(detection: Detection) -> Result<Self, GetTimezoneError> {
+        let rule = match detection.posix_tz.or_else(|| posix_tz_for(&detection.name)) {
+            Some(rule) => PosixTz::parse(&rule)?,
+            None => return Err(GetTimezoneError::InvalidTimezone(detection.name)),
+        };
+        Ok(Self {
+            name: detection.name,
+            tzif: Tzif::from_posix_tz(rule),
+        })
+    }
+
+    /// Parse the contents of a TZif file, e.g. of `/usr/share/zoneinfo/Europe/Berlin`, as the
+    /// time zone `name`.
+    ///
+    /// All versions of the format are supported, see
+    /// [RFC 8536](https://datatracker.ietf.org/doc/html/rfc8536).
+    pub fn from_tzif(name: impl Into<String>, data: &[u8]) -> Result<Self, GetTimezoneError> {
+        let name = name.into();
+        match Tzif::parse(data) {
+            Some(tzif) => Ok(Self { name, tzif }),
+            None => Err(GetTimezoneError::FailedParsingString {
+                context: Context::Value(name),
+                reason: "invalid TZif file".to_owned(),
+            }),
+        }
+    }
+
+    /// The IANA time zone identifier, e.g. `"Europe/Berlin"`.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// The offset in seconds east of UTC at `unix_seconds`, e.g. `3600` for `+01:00`.
+    pub fn offset_at(&self, unix_seconds: i64) -> i32 {
+        self.local_type_at(unix_seconds).0.utc_offset
+    }
+
+    /// The abbreviation of the local time at `unix_seconds`, e.g. `"CET"`.
+    ///
+    /// Many time zones use numeric abbreviations like `"+03"`.
+    pub fn abbreviation_at(&self, unix_seconds: i64) -> &str {
+        &self.local_type_at(unix_seconds).0.abbreviation
+    }
+
+    /// Whether daylight saving time is in effect at `unix_seconds`.
+    pub fn is_dst_at(&self, unix_seconds: i64) -> bool {
+        self.local_type_at(unix_seconds).1
+    }
+
+    /// The transitions at or after `start`, and before `end`, in ascending order.
+    ///
+    /// After the last transition in the TZif file, the transitions are generated from the POSIX
+    /// TZ rule in its footer, so they continue past 2037. Transitions that change neither the
+    /// offset, nor the abbreviation, nor daylight saving time are skipped.
+    ///
+    /// ```rust
+    /// # let data = std::fs::read("tests/fixtures/tzif/Europe/Berlin").unwrap();
+    /// # let zone = iana_time_zone::LocalZone::from_tzif("Europe/Berlin", &data)?;
+    /// // 2024
+    /// let transitions = zone.transitions_between(1_704_067_200, 1_735_689_600);
+    /// assert_eq!(transitions.len(), 2);
+    /// assert_eq!(transitions[0].unix_seconds, 1_711_846_800);
+    /// assert_eq!(transitions[0].abbreviation_before, "CET");
+    /// assert_eq!(transitions[0].abbreviation_after, "CEST");
+    /// # Ok::<(), iana_time_zone::GetTimezoneError>(())
+    /// ```
+    pub fn transitions_between(&self, start: i64, end: i64) -> Vec<ZoneTransition> {
+        self.tzif
+            .transitions_from(start)
+            .take_while(|&time| time < end)
+            .map(|time| self.transition(time))
+            .collect()
+    }
+
+    /// The first transition after `unix_seconds`, or `None` if the offset and the abbreviation
+    /// never change again.
+    pub fn next_transition(&self, unix_seconds: i64) -> Option<ZoneTransition> {
+        let time = self
+            .tzif
+            .transitions_from(unix_seconds.checked_add(1)?)
+            .next()?;
+        Some(self.transition(time))
+    }
+
+    fn transition(&self, unix_seconds: i64) -> ZoneTransition {
+        let (before, is_dst_before) = self.local_type_at(unix_seconds.saturating_sub(1));
+        let (after, is_dst_after) = self.local_type_at(unix_seconds);
+        ZoneTransition {
+            unix_seconds,
+            offset_before: before.utc_offset,
+            offset_after: after.utc_offset,
+            abbreviation_before: before.abbreviation.clone(),
+            abbreviation_after: after.abbreviation.clone(),
+            is_dst_before,
+            is_dst_after,
+        }
+    }
+
+    fn local_type_at(&self, unix_seconds: i64) -> (&LocalType, bool) {
+        self.tzif.local_type_at(unix_seconds)
+    }
+}
+
+/// A change of the UTC offset, the abbreviation or daylight saving time of a [`LocalZone`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct ZoneTransition {
+    /// The instant of the transition, in seconds since the Unix epoch.
+    pub unix_seconds: i64,
+    /// The offset in seconds east of UTC before the transition.
+    pub offset_before: i32,
+    /// The offset in seconds east of UTC from the transition on.
+    pub offset_after: i32,
+    /// The abbreviation of the local time before the transition, e.g. `"CET"`.
+    pub abbreviation_before: String,
+    /// The abbreviation of the local time from the transition on, e.g. `"CEST"`.
+    pub abbreviation_after: String,
+    /// Whether daylight saving time was in effect before the transition.
+    pub is_dst_before: bool,
+    /// Whether daylight saving time is in effect from the transition on.
+    pub is_dst_after: bool,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{LocalZone, ZoneTransition};
+    use crate::{Detection, Source};
+
+    #[test]
+    fn test_from_tzif() {
+        let data = include_bytes!("../tests/fixtures/tzif/Australia/Sydney");
+        let zone = LocalZone::from_tzif("Australia/Sydney", data).unwrap();
+        assert_eq!(zone.name(), "Australia/Sydney");
+        // 2024-01-01, and 2024-07-01
+        assert_eq!(zone.offset_at(1_704_067_200), 11 * 3600);
+        assert_eq!(zone.abbreviation_at(1_704_067_200), "AEDT");
+        assert!(zone.is_dst_at(1_704_067_200));
+        assert_eq!(zone.offset_at(1_719_792_000), 10 * 3600);
+        assert_eq!(zone.abbreviation_at(1_719_792_000), "AEST");
+        assert!(!zone.is_dst_at(1_719_792_000));
+
+        LocalZone::from_tzif("Australia/Sydney", &data[..100]).unwrap_err();
+    }
+
+    #[test]
+    fn test_embedded() {
+        let detection = Detection::new("America/New_York".to_owned(), Source::OsApi);
+        let zone = LocalZone::embedded(detection).unwrap();
+        assert_eq!(zone.abbreviation_at(1_704_067_200), "EST");
+        assert_eq!(zone.abbreviation_at(1_719_792_000), "EDT");
+
+        let detection = Detection::new("Mars/Olympus_Mons".to_owned(), Source::OsApi);
+        LocalZone::embedded(detection).unwrap_err();
+    }
+
+    #[test]
+    fn test_transitions() {
+        let data = include_bytes!("../tests/fixtures/tzif/Australia/Sydney");
+        let zone = LocalZone::from_tzif("Australia/Sydney", data).unwrap();
+        // 2024
+        let transitions = zone.transitions_between(1_704_067_200, 1_735_689_600);
+        assert_eq!(
+            transitions,
+            [
+                ZoneTransition {
+                    unix_seconds: 1_712_419_200,
+                    offset_before: 11 * 3600,
+                    offset_after: 10 * 3600,
+                    abbreviation_before: "AEDT".to_owned(),
+                    abbreviation_after: "AEST".to_owned(),
+                    is_dst_before: true,
+                    is_dst_after: false,
+                },
+                ZoneTransition {
+                    unix_seconds: 1_728_144_000,
+                    offset_before: 10 * 3600,
+                    offset_after: 11 * 3600,
+                    abbreviation_before: "AEST".to_owned(),
+                    abbreviation_after: "AEDT".to_owned(),
+                    is_dst_before: false,
+                    is_dst_after: true,
+                },
+            ]
+        );
+        assert_eq!(
+            zone.next_transition(1_704_067_200).as_ref(),
+            transitions.first()
+        );
+        assert_eq!(
+            zone.next_transition(1_712_419_199).as_ref(),
+            transitions.first()
+        );
+        assert_eq!(
+            zone.next_transition(1_712_419_200).as_ref(),
+            transitions.last()
+        );
+
+        // 2100-04-04, from the footer.
+        let transition = zone.next_transition(4_102_444_800).unwrap();
+        assert_eq!(transition.unix_seconds, 4_110_451_200);
+        assert_eq!(transition.abbreviation_after, "AEST");
+
+        // No daylight saving time, and permanent daylight saving time.
+        let detection = Detection::new("Asia/Kolkata".to_owned(), Source::OsApi);
+        let zone = LocalZone::embedded(detection).unwrap();
+        assert_eq!(zone.next_transition(0), None);
+        let detection = Detection {
+            posix_tz: Some("EST5EDT,0/0,J365/25".to_owned()),
+            ..Detection::new("America/New_York".to_owned(), Source::EnvVar)
+        };
+        let zone = LocalZone::embedded(detection).unwrap();
+        assert!(zone.is_dst_at(1_704_067_200));
+        assert_eq!(zone.next_transition(0), None);
+        assert_eq!(zone.transitions_between(0, 4_102_444_800), []);
+    }
+
+    #[test]
+    fn test_load() {
+        let zone = LocalZone::load().unwrap();
+        assert_eq!(zone.name(), crate::get_timezone().unwrap());
+    }
+}
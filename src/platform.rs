@@ -1,5 +1,7 @@
-pub fn get_timezone_inner() -> Result<String, crate::GetTimezoneError> {
-    Err(crate::GetTimezoneError::OsError)
+pub(crate) const SOURCES: &[crate::BuiltinSource] = &[];
+
+pub fn detect_inner() -> Result<crate::Detection, crate::GetTimezoneError> {
+    Err(crate::GetTimezoneError::Unsupported)
 }
 
 #[cfg(not(feature = "fallback"))]
@@ -0,0 +1,772 @@
+//! POSIX TZ rule strings, e.g. `"CET-1CEST,M3.5.0,M10.5.0/3"`.
+//!
+//! Some systems store a rule string instead of an IANA time zone name: OpenWrt in the option
+//! `timezone` of `/etc/config/system`, illumos and AIX in `TZ`. The rule only describes the
+//! current offsets and daylight saving time transitions, so it can match multiple time zones.
+
+use std::cmp::Reverse;
+use std::fmt;
+use std::str::FromStr;
+
+use crate::tzdata::POSIX_TZ;
+use crate::tzdb::{aliases, canonicalize};
+use crate::{Context, GetTimezoneError};
+
+/// Return the POSIX TZ rule string of the current rules of an IANA time zone.
+///
+/// This is the reverse of [`PosixTz::iana_candidates()`], e.g. for systems that only understand
+/// rule strings, like BusyBox or microcontrollers. On Unix-like systems, the rule is read from
+/// the footer of the file in the installed zoneinfo database, which may be more recent. Otherwise
+/// an embedded table is used. `None` is returned if `name` is unknown.
+///
+/// ```rust
+/// use iana_time_zone::posix_tz_for;
+///
+/// assert_eq!(posix_tz_for("Europe/Berlin").unwrap(), "CET-1CEST,M3.5.0,M10.5.0/3");
+/// assert_eq!(posix_tz_for("Asia/Calcutta").unwrap(), "IST-5:30");
+///
+/// let tz_str = iana_time_zone::get_timezone()?;
+/// println!("TZ={}", posix_tz_for(&tz_str).unwrap_or_default());
+/// # Ok::<(), iana_time_zone::GetTimezoneError>(())
+/// ```
+pub fn posix_tz_for(name: &str) -> Option<String> {
+    #[cfg(unix)]
+    {
+        let fs = crate::filesystem::StdFs;
+        let installed = crate::unix::zoneinfo_file(&fs, std::path::Path::new("/"), name)
+            .and_then(|path| crate::zoneinfo::read_footer(&fs, &path))
+            .filter(|rule| PosixTz::parse(rule).is_ok());
+        if installed.is_some() {
+            return installed;
+        }
+    }
+
+    let name = canonicalize(name);
+    POSIX_TZ
+        .binary_search_by_key(&name, |&(zone, _)| zone)
+        .ok()
+        .map(|idx| POSIX_TZ[idx].1.to_owned())
+}
+
+/// A parsed POSIX TZ rule string.
+///
+/// See <https://pubs.opengroup.org/onlinepubs/9799919799/basedefs/V1_chap08.html> and
+/// [RFC 8536](https://datatracker.ietf.org/doc/html/rfc8536#section-3.3.1), which extends the
+/// range of the transition times.
+///
+/// Two rules are equal if they describe the same offsets and transitions, e.g. `"CET-1CEST"`
+/// uses the implied default transitions, and the DST offset `"-2"` is implied.
+///
+/// ```rust
+/// use iana_time_zone::PosixTz;
+///
+/// let rule: PosixTz = "CET-1CEST-2,M3.5.0/2:00,M10.5.0/3".parse()?;
+/// assert_eq!(rule.to_string(), "CET-1CEST,M3.5.0,M10.5.0/3");
+/// assert!(rule.iana_candidates().contains(&"Europe/Berlin"));
+/// # Ok::<(), iana_time_zone::GetTimezoneError>(())
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct PosixTz {
+    std: LocalType,
+    dst: Option<Dst>,
+}
+
+/// A local time type, i.e. an abbreviation and its offset.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub(crate) struct LocalType {
+    /// The abbreviation without angle brackets.
+    pub(crate) abbreviation: String,
+    /// The offset in seconds east of UTC, i.e. the inverse of the POSIX offset.
+    pub(crate) utc_offset: i32,
+}
+
+/// The daylight saving time of a rule, and when it is in effect.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub(crate) struct Dst {
+    pub(crate) local_type: LocalType,
+    pub(crate) start: Transition,
+    pub(crate) end: Transition,
+}
+
+/// A yearly transition.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub(crate) struct Transition {
+    pub(crate) date: RuleDate,
+    /// The local time of the transition in seconds after midnight, in `-167..=167` hours.
+    pub(crate) time: i32,
+}
+
+/// The day of the year of a transition.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub(crate) enum RuleDate {
+    /// `Jn`: the day `1..=365`, where February 29 is never counted.
+    Julian1(u16),
+    /// `n`: the day `0..=365`, where February 29 is counted in leap years.
+    Julian0(u16),
+    /// `Mm.w.d`: the weekday `d` (`0` is Sunday) of the week `w` (`5` is the last one) of the
+    /// month `m`.
+    MonthWeekDay { month: u8, week: u8, weekday: u8 },
+}
+
+/// The default transitions if a rule has DST but no transitions, like in tzcode.
+const DEFAULT_TRANSITIONS: &str = "M3.2.0,M11.1.0";
+
+/// The default time of a transition.
+const DEFAULT_TIME: i32 = 2 * 3600;
+
+impl PosixTz {
+    /// Parse a POSIX TZ rule string.
+    ///
+    /// A leading `:`, which denotes an implementation defined value, is not accepted.
+    pub fn parse(s: &str) -> Result<Self, GetTimezoneError> {
+        Parser(s.as_bytes())
+            .rule()
+            .ok_or_else(|| GetTimezoneError::FailedParsingString {
+                context: Context::Value(s.to_owned()),
+                reason: "invalid POSIX TZ rule string".to_owned(),
+            })
+    }
+
+    /// Return the IANA time zones whose current rules are the same as this rule.
+    ///
+    /// The zones are ordered by preference: the primary zones come first, starting with the
+    /// ones that the IANA time zone database names after the rule, e.g. `"America/New_York"`
+    /// for `"EST5EDT,M3.2.0,M11.1.0"`, or `"Etc/GMT-3"` for `"<+03>-3"`. Their backward
+    /// compatible aliases follow.
+    ///
+    /// The rules are compared using an embedded table, so no installed tzdata is needed. Past
+    /// rules are not compared, so the zones might have had different offsets in the past.
+    pub fn iana_candidates(&self) -> Vec<&'static str> {
+        let mut zones: Vec<&'static str> = POSIX_TZ
+            .iter()
+            .filter(|&&(_, rule)| PosixTz::parse(rule).map_or(false, |rule| &rule == self))
+            .map(|&(zone, _)| zone)
+            .collect();
+
+        // Prefer the zones that are named after the rule, then the zones with the most merged
+        // zones, which are usually the best known ones.
+        let named = self.named_zones();
+        zones.sort_by_key(|&zone| {
+            let named = named.iter().position(|&name| name == zone);
+            (named.is_none(), named, Reverse(aliases(zone).len()), zone)
+        });
+
+        let primary = zones.len();
+        for idx in 0..primary {
+            zones.extend(aliases(zones[idx]));
+        }
+        zones
+    }
+
+    /// The zones and links that are named after this rule, in order of preference.
+    fn named_zones(&self) -> Vec<&'static str> {
+        let mut names = Vec::new();
+        if self.dst.is_some() {
+            // E.g. "EST5EDT".
+            let rule = self.to_string();
+            names.push(rule[..rule.find(',').unwrap_or(rule.len())].to_owned());
+        }
+        // E.g. "CET" or "UTC".
+        names.push(self.std.abbreviation.clone());
+        if self.dst.is_none() && self.std.utc_offset % 3600 == 0 {
+            // The signs of the "Etc/GMT" zones are inverted, like in POSIX.
+            names.push(match self.std.utc_offset / 3600 {
+                0 => "Etc/GMT".to_owned(),
+                hours => format!("Etc/GMT{:+}", -hours),
+            });
+        }
+        names
+            .iter()
+            .filter_map(|name| {
+                let name = canonicalize(name);
+                POSIX_TZ
+                    .binary_search_by_key(&name, |&(zone, _)| zone)
+                    .ok()
+                    .map(|idx| POSIX_TZ[idx].0)
+            })
+            .collect()
+    }
+
+    /// The local time type at `unix_time`, and whether it is daylight saving time.
+    pub(crate) fn local_type_at(&self, unix_time: i64) -> (&LocalType, bool) {
+        let dst = match &self.dst {
+            Some(dst) => dst,
+            None => return (&self.std, false),
+        };
+        let unix_time = unix_time.clamp(-MAX_TIME, MAX_TIME);
+        let year = year_of(unix_time + i64::from(self.std.utc_offset));
+        let (start, end) = dst.period(&self.std, year);
+        // On the southern hemisphere, daylight saving time spans the turn of the year.
+        let is_dst = if start <= end {
+            start <= unix_time && unix_time < end
+        } else {
+            unix_time < end || start <= unix_time
+        };
+        if is_dst {
+            (&dst.local_type, true)
+        } else {
+            (&self.std, false)
+        }
+    }
+
+    /// The times of the transitions of this rule at or after `from`, as Unix times in ascending
+    /// order.
+    ///
+    /// Transitions that do not change the local time type are skipped, so a rule of permanent
+    /// daylight saving time has none.
+    pub(crate) fn transitions_from(&self, from: i64) -> impl Iterator<Item = i64> + '_ {
+        let from = from.clamp(-MAX_TIME, MAX_TIME);
+        let years = year_of(from) - 1..=year_of(MAX_TIME);
+        self.dst
+            .iter()
+            .filter(move |_| self.changes_in(2000) || self.changes_in(2001))
+            .flat_map(move |dst| {
+                years.clone().flat_map(move |year| {
+                    let (start, end) = dst.period(&self.std, year);
+                    [start.min(end), start.max(end)]
+                })
+            })
+            .filter(move |&time| time >= from && self.changes_at(time))
+    }
+
+    /// Whether the local time type changes in `year`.
+    fn changes_in(&self, year: i64) -> bool {
+        self.dst.as_ref().map_or(false, |dst| {
+            let (start, end) = dst.period(&self.std, year);
+            self.changes_at(start) || self.changes_at(end)
+        })
+    }
+
+    fn changes_at(&self, time: i64) -> bool {
+        self.local_type_at(time - 1) != self.local_type_at(time)
+    }
+}
+
+impl Dst {
+    /// The start and end of daylight saving time in `year` as Unix times.
+    pub(crate) fn period(&self, std: &LocalType, year: i64) -> (i64, i64) {
+        (
+            self.start.unix_time(year, std.utc_offset),
+            self.end.unix_time(year, self.local_type.utc_offset),
+        )
+    }
+}
+
+impl Transition {
+    /// The Unix time of the transition in `year`, if the local time is `utc_offset` before it.
+    fn unix_time(&self, year: i64, utc_offset: i32) -> i64 {
+        self.date.day(year) * SECONDS_PER_DAY + i64::from(self.time) - i64::from(utc_offset)
+    }
+}
+
+impl RuleDate {
+    /// The day of the transition in `year` as days since 1970-01-01.
+    fn day(&self, year: i64) -> i64 {
+        let january_1 = days_from_civil(year, 1, 1);
+        match *self {
+            RuleDate::Julian1(day) => {
+                let leap_day = is_leap_year(year) && day >= 60;
+                january_1 + i64::from(day) - 1 + i64::from(leap_day)
+            }
+            RuleDate::Julian0(day) => january_1 + i64::from(day),
+            RuleDate::MonthWeekDay {
+                month,
+                week,
+                weekday,
+            } => {
+                let first = days_from_civil(year, month, 1);
+                // 1970-01-01 was a Thursday.
+                let first_weekday = (first + 4).rem_euclid(7);
+                let mut day = first
+                    + (i64::from(weekday) - first_weekday).rem_euclid(7)
+                    + 7 * (i64::from(week) - 1);
+                // The week 5 is the last week, which may be the 4th one.
+                if day >= first + month_len(year, month) {
+                    day -= 7;
+                }
+                day
+            }
+        }
+    }
+}
+
+const SECONDS_PER_DAY: i64 = 86_400;
+
+/// Unix times are clamped to about ±18 billion years, so the calendar calculations cannot
+/// overflow.
+const MAX_TIME: i64 = 1 << 59;
+
+/// The days since 1970-01-01 of a date in the proleptic Gregorian calendar.
+///
+/// See <https://howardhinnant.github.io/date_algorithms.html#days_from_civil>.
+fn days_from_civil(year: i64, month: u8, day: u8) -> i64 {
+    let (year, month) = match month {
+        1 | 2 => (year - 1, i64::from(month) + 9),
+        _ => (year, i64::from(month) - 3),
+    };
+    let era = year.div_euclid(400);
+    let year_of_era = year.rem_euclid(400);
+    let day_of_year = (153 * month + 2) / 5 + i64::from(day) - 1;
+    let day_of_era = 365 * year_of_era + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    146_097 * era + day_of_era - 719_468
+}
+
+/// The year of a Unix time in the proleptic Gregorian calendar.
+///
+/// See <https://howardhinnant.github.io/date_algorithms.html#civil_from_days>.
+fn year_of(unix_time: i64) -> i64 {
+    let days = unix_time.div_euclid(SECONDS_PER_DAY) + 719_468;
+    let era = days.div_euclid(146_097);
+    let day_of_era = days.rem_euclid(146_097);
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    // The year starts in March, so January and February belong to the next year.
+    let year = 400 * era + year_of_era;
+    if day_of_year >= 306 {
+        year + 1
+    } else {
+        year
+    }
+}
+
+fn is_leap_year(year: i64) -> bool {
+    year % 4 == 0 && (year % 100 != 0 || year % 400 == 0)
+}
+
+fn month_len(year: i64, month: u8) -> i64 {
+    match month {
+        2 if is_leap_year(year) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+impl FromStr for PosixTz {
+    type Err = GetTimezoneError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s)
+    }
+}
+
+impl fmt::Display for PosixTz {
+    /// Format the rule in the shortest form, like `zic` does in the footer of TZif files.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.std)?;
+        if let Some(dst) = &self.dst {
+            write_abbreviation(f, &dst.local_type.abbreviation)?;
+            if dst.local_type.utc_offset != self.std.utc_offset + 3600 {
+                write_duration(f, -dst.local_type.utc_offset)?;
+            }
+            write!(f, ",{},{}", dst.start, dst.end)?;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for LocalType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write_abbreviation(f, &self.abbreviation)?;
+        write_duration(f, -self.utc_offset)
+    }
+}
+
+impl fmt::Display for Transition {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.date {
+            RuleDate::Julian1(day) => write!(f, "J{}", day)?,
+            RuleDate::Julian0(day) => write!(f, "{}", day)?,
+            RuleDate::MonthWeekDay {
+                month,
+                week,
+                weekday,
+            } => write!(f, "M{}.{}.{}", month, week, weekday)?,
+        }
+        if self.time != DEFAULT_TIME {
+            f.write_str("/")?;
+            write_duration(f, self.time)?;
+        }
+        Ok(())
+    }
+}
+
+fn write_abbreviation(f: &mut fmt::Formatter<'_>, abbreviation: &str) -> fmt::Result {
+    if abbreviation.bytes().all(|b| b.is_ascii_alphabetic()) {
+        f.write_str(abbreviation)
+    } else {
+        write!(f, "<{}>", abbreviation)
+    }
+}
+
+fn write_duration(f: &mut fmt::Formatter<'_>, seconds: i32) -> fmt::Result {
+    if seconds < 0 {
+        f.write_str("-")?;
+    }
+    let seconds = seconds.unsigned_abs();
+    write!(f, "{}", seconds / 3600)?;
+    match (seconds / 60 % 60, seconds % 60) {
+        (0, 0) => Ok(()),
+        (minutes, 0) => write!(f, ":{:02}", minutes),
+        (minutes, seconds) => write!(f, ":{:02}:{:02}", minutes, seconds),
+    }
+}
+
+/// A recursive descent parser, which returns `None` if the input does not match.
+struct Parser<'a>(&'a [u8]);
+
+impl Parser<'_> {
+    fn rule(&mut self) -> Option<PosixTz> {
+        let abbreviation = self.abbreviation()?;
+        let std = LocalType {
+            abbreviation,
+            utc_offset: -self.duration(24)?,
+        };
+        if self.0.is_empty() {
+            return Some(PosixTz { std, dst: None });
+        }
+
+        let abbreviation = self.abbreviation()?;
+        let utc_offset = match self.0.first() {
+            None | Some(b',') => std.utc_offset + 3600,
+            Some(_) => -self.duration(24)?,
+        };
+        let (start, end) = if self.0.is_empty() {
+            let mut parser = Parser(DEFAULT_TRANSITIONS.as_bytes());
+            (parser.transition()?, parser.next_transition()?)
+        } else {
+            self.expect(b',')?;
+            (self.transition()?, self.next_transition()?)
+        };
+        if !self.0.is_empty() {
+            return None;
+        }
+
+        let local_type = LocalType {
+            abbreviation,
+            utc_offset,
+        };
+        Some(PosixTz {
+            std,
+            dst: Some(Dst {
+                local_type,
+                start,
+                end,
+            }),
+        })
+    }
+
+    /// Parse an abbreviation of at least three letters, or a quoted `<...>` one, which may also
+    /// contain digits and signs.
+    fn abbreviation(&mut self) -> Option<String> {
+        let (abbreviation, rest) = if let Some(quoted) = self.0.strip_prefix(b"<") {
+            let len = quoted.iter().position(|&b| b == b'>')?;
+            let (abbreviation, rest) = quoted.split_at(len);
+            if !abbreviation
+                .iter()
+                .all(|&b| b.is_ascii_alphanumeric() || b == b'+' || b == b'-')
+            {
+                return None;
+            }
+            (abbreviation, &rest[1..])
+        } else {
+            let len = self
+                .0
+                .iter()
+                .position(|b| !b.is_ascii_alphabetic())
+                .unwrap_or(self.0.len());
+            self.0.split_at(len)
+        };
+        if abbreviation.len() < 3 {
+            return None;
+        }
+        self.0 = rest;
+        String::from_utf8(abbreviation.to_vec()).ok()
+    }
+
+    /// Parse `[+-]hh[:mm[:ss]]` as seconds, where `hh` is at most `max_hours`.
+    fn duration(&mut self, max_hours: i32) -> Option<i32> {
+        let sign = match self.0.first() {
+            Some(b'-') => -1,
+            Some(b'+') => 1,
+            _ => 0,
+        };
+        if sign != 0 {
+            self.0 = &self.0[1..];
+        }
+        let hours = self.number(3, max_hours)?;
+        let mut seconds = hours * 3600;
+        if self.0.first() == Some(&b':') {
+            self.0 = &self.0[1..];
+            seconds += self.number(2, 59)? * 60;
+            if self.0.first() == Some(&b':') {
+                self.0 = &self.0[1..];
+                seconds += self.number(2, 59)?;
+            }
+        }
+        Some(if sign < 0 { -seconds } else { seconds })
+    }
+
+    /// Parse `,date[/time]`.
+    fn next_transition(&mut self) -> Option<Transition> {
+        self.expect(b',')?;
+        self.transition()
+    }
+
+    /// Parse `date[/time]`.
+    fn transition(&mut self) -> Option<Transition> {
+        let date = match self.0.first()? {
+            b'J' => {
+                self.0 = &self.0[1..];
+                RuleDate::Julian1(self.number(3, 365).filter(|&day| day >= 1)? as u16)
+            }
+            b'M' => {
+                self.0 = &self.0[1..];
+                let month = self.number(2, 12).filter(|&month| month >= 1)? as u8;
+                self.expect(b'.')?;
+                let week = self.number(1, 5).filter(|&week| week >= 1)? as u8;
+                self.expect(b'.')?;
+                let weekday = self.number(1, 6)? as u8;
+                RuleDate::MonthWeekDay {
+                    month,
+                    week,
+                    weekday,
+                }
+            }
+            _ => RuleDate::Julian0(self.number(3, 365)? as u16),
+        };
+        let time = match self.0.first() {
+            Some(b'/') => {
+                self.0 = &self.0[1..];
+                self.duration(167)?
+            }
+            _ => DEFAULT_TIME,
+        };
+        Some(Transition { date, time })
+    }
+
+    /// Parse a decimal number of up to `max_digits` digits, which is at most `max`.
+    fn number(&mut self, max_digits: usize, max: i32) -> Option<i32> {
+        let len = self
+            .0
+            .iter()
+            .take(max_digits + 1)
+            .position(|b| !b.is_ascii_digit())
+            .unwrap_or_else(|| self.0.len().min(max_digits + 1));
+        if len == 0 || len > max_digits {
+            return None;
+        }
+        let (digits, rest) = self.0.split_at(len);
+        let number = digits
+            .iter()
+            .fold(0, |number, &digit| number * 10 + i32::from(digit - b'0'));
+        self.0 = rest;
+        Some(number).filter(|&number| number <= max)
+    }
+
+    fn expect(&mut self, byte: u8) -> Option<()> {
+        self.0 = self.0.strip_prefix(&[byte])?;
+        Some(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{days_from_civil, posix_tz_for, year_of, PosixTz, RuleDate, POSIX_TZ};
+
+    #[test]
+    fn test_parse() {
+        let rule = PosixTz::parse("CET-1CEST,M3.5.0,M10.5.0/3").unwrap();
+        assert_eq!(rule.std.abbreviation, "CET");
+        assert_eq!(rule.std.utc_offset, 3600);
+        let dst = rule.dst.as_ref().unwrap();
+        assert_eq!(dst.local_type.abbreviation, "CEST");
+        assert_eq!(dst.local_type.utc_offset, 7200);
+        assert_eq!(
+            dst.start.date,
+            RuleDate::MonthWeekDay {
+                month: 3,
+                week: 5,
+                weekday: 0
+            }
+        );
+        assert_eq!(dst.start.time, 7200);
+        assert_eq!(dst.end.time, 3 * 3600);
+
+        let rule = PosixTz::parse("<+0330>-3:30").unwrap();
+        assert_eq!(rule.std.abbreviation, "+0330");
+        assert_eq!(rule.std.utc_offset, 3 * 3600 + 30 * 60);
+        assert!(rule.dst.is_none());
+
+        let rule = PosixTz::parse("<-02>2<-01>,M3.5.0/-1,J365/25:30:15").unwrap();
+        let dst = rule.dst.as_ref().unwrap();
+        assert_eq!(dst.start.time, -3600);
+        assert_eq!(dst.end.date, RuleDate::Julian1(365));
+        assert_eq!(dst.end.time, 25 * 3600 + 30 * 60 + 15);
+
+        // The implied DST offset and transitions are the same as the explicit ones.
+        assert_eq!(
+            PosixTz::parse("EST5EDT").unwrap(),
+            PosixTz::parse("EST+5EDT+4:00,M3.2.0/2,M11.1.0/02:00:00").unwrap(),
+        );
+        assert_ne!(
+            PosixTz::parse("EST5EDT").unwrap(),
+            PosixTz::parse("EST5EDT,0,365").unwrap(),
+        );
+
+        for invalid in [
+            "",
+            ":Europe/Berlin",
+            "Europe/Berlin",
+            "UTC",
+            "CE-1",
+            "<CE>-1",
+            "<C E T>-1",
+            "CET-25",
+            "CET-1:60",
+            "CET-1CEST,M3.5.0",
+            "CET-1CEST,M13.5.0,M10.5.0",
+            "CET-1CEST,M3.6.0,M10.5.0",
+            "CET-1CEST,M3.5.7,M10.5.0",
+            "CET-1CEST,J0,J365",
+            "CET-1CEST,0,366",
+            "CET-1CEST,M3.5.0/168,M10.5.0",
+            "CET-1CEST,M3.5.0,M10.5.0/3 ",
+        ] {
+            PosixTz::parse(invalid).unwrap_err();
+        }
+    }
+
+    #[test]
+    fn test_display() {
+        for &(_, rule) in POSIX_TZ {
+            assert_eq!(PosixTz::parse(rule).unwrap().to_string(), rule);
+        }
+        for (rule, formatted) in [
+            ("UTC0", "UTC0"),
+            ("EST5EDT", "EST5EDT,M3.2.0,M11.1.0"),
+            ("<ABC>+1:30:05", "ABC1:30:05"),
+            (
+                "<+00>0<+02>-2,M3.5.0/1,M10.5.0/3",
+                "<+00>0<+02>-2,M3.5.0/1,M10.5.0/3",
+            ),
+            ("IST-1GMT0,M10.5.0,M3.5.0/1", "IST-1GMT0,M10.5.0,M3.5.0/1"),
+        ] {
+            assert_eq!(PosixTz::parse(rule).unwrap().to_string(), formatted);
+        }
+    }
+
+    #[test]
+    fn test_iana_candidates() {
+        let candidates = |rule: &str| PosixTz::parse(rule).unwrap().iana_candidates();
+
+        assert_eq!(candidates("EST5EDT,M3.2.0,M11.1.0")[0], "America/New_York");
+        assert_eq!(
+            candidates("PST8PDT,M3.2.0,M11.1.0")[0],
+            "America/Los_Angeles"
+        );
+        assert_eq!(
+            candidates("CET-1CEST,M3.5.0,M10.5.0/3")[0],
+            "Europe/Brussels"
+        );
+        assert_eq!(candidates("<+03>-3")[0], "Etc/GMT-3");
+        assert_eq!(candidates("UTC0")[0], "Etc/UTC");
+        assert_eq!(candidates("JST-9"), ["Asia/Tokyo", "Japan"]);
+        assert_eq!(candidates("IST-5:30"), ["Asia/Kolkata", "Asia/Calcutta"]);
+        assert!(candidates("<+0123>-1:23").is_empty());
+
+        // All primary zones come before the aliases.
+        let zones = candidates("CET-1CEST,M3.5.0,M10.5.0/3");
+        let berlin = zones
+            .iter()
+            .position(|&zone| zone == "Europe/Berlin")
+            .unwrap();
+        let oslo = zones
+            .iter()
+            .position(|&zone| zone == "Europe/Oslo")
+            .unwrap();
+        let paris = zones
+            .iter()
+            .position(|&zone| zone == "Europe/Paris")
+            .unwrap();
+        assert!(berlin < paris && paris < oslo);
+    }
+
+    #[test]
+    fn test_posix_tz_for() {
+        assert_eq!(
+            posix_tz_for("America/New_York").unwrap(),
+            "EST5EDT,M3.2.0,M11.1.0"
+        );
+        assert_eq!(
+            posix_tz_for("US/Eastern").unwrap(),
+            "EST5EDT,M3.2.0,M11.1.0"
+        );
+        assert_eq!(posix_tz_for("Etc/GMT-14").unwrap(), "<+14>-14");
+        assert_eq!(posix_tz_for("Not/A_Zone"), None);
+        assert_eq!(posix_tz_for("../../etc/passwd"), None);
+
+        // The rules of all zones resolve back to the zone.
+        for &(zone, rule) in POSIX_TZ {
+            assert!(PosixTz::parse(rule)
+                .unwrap()
+                .iana_candidates()
+                .contains(&zone));
+        }
+    }
+
+    #[test]
+    fn test_calendar() {
+        assert_eq!(days_from_civil(1970, 1, 1), 0);
+        assert_eq!(days_from_civil(2024, 3, 1), 19_783);
+        assert_eq!(days_from_civil(1600, 2, 29), -135_081);
+        assert_eq!(year_of(0), 1970);
+        assert_eq!(year_of(-1), 1969);
+        assert_eq!(year_of(1_709_251_199), 2024); // 2024-02-29 23:59:59
+        assert_eq!(year_of(days_from_civil(-4713, 11, 24) * 86_400), -4713);
+    }
+
+    #[test]
+    fn test_local_type_at() {
+        let at = |rule: &str, unix_time: i64| {
+            let rule = PosixTz::parse(rule).unwrap();
+            let (local_type, is_dst) = rule.local_type_at(unix_time);
+            (
+                local_type.abbreviation.clone(),
+                local_type.utc_offset,
+                is_dst,
+            )
+        };
+        let berlin = "CET-1CEST,M3.5.0,M10.5.0/3";
+        assert_eq!(at(berlin, 1_711_846_799), ("CET".to_owned(), 3600, false));
+        assert_eq!(at(berlin, 1_711_846_800), ("CEST".to_owned(), 7200, true));
+        assert_eq!(at(berlin, 1_729_990_799), ("CEST".to_owned(), 7200, true));
+        assert_eq!(at(berlin, 1_729_990_800), ("CET".to_owned(), 3600, false));
+
+        // Daylight saving time spans the turn of the year.
+        let sydney = "AEST-10AEDT,M10.1.0,M4.1.0/3";
+        assert_eq!(at(sydney, 1_712_419_199), ("AEDT".to_owned(), 39_600, true));
+        assert_eq!(
+            at(sydney, 1_712_419_200),
+            ("AEST".to_owned(), 36_000, false)
+        );
+        assert_eq!(
+            at(sydney, 1_728_143_999),
+            ("AEST".to_owned(), 36_000, false)
+        );
+        assert_eq!(at(sydney, 1_728_144_000), ("AEDT".to_owned(), 39_600, true));
+        assert!(at(sydney, 1_704_067_200).2); // 2024-01-01
+
+        // Permanent daylight saving time, as written by zic.
+        let permanent = "XXX3EDT4,0/0,J365/25";
+        assert_eq!(at(permanent, 0), ("EDT".to_owned(), -4 * 3600, true));
+        assert!(at(permanent, 1_704_067_200).2);
+
+        assert_eq!(
+            at("<+0530>-5:30", i64::MIN),
+            ("+0530".to_owned(), 19_800, false)
+        );
+        assert!(!at(berlin, i64::MAX).2);
+    }
+}
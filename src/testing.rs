@@ -0,0 +1,165 @@
+//! Overrides of the detected time zone, to make tests deterministic.
+
+use std::cell::RefCell;
+use std::marker::PhantomData;
+use std::mem;
+use std::sync::{PoisonError, RwLock};
+
+use crate::lazy::Lazy;
+use crate::{Detection, Source};
+
+static GLOBAL: Lazy<RwLock<Option<String>>> = Lazy::new(|| RwLock::new(None));
+
+thread_local! {
+    static LOCAL: RefCell<Option<String>> = const { RefCell::new(None) };
+}
+
+/// Make [`get_timezone()`](crate::get_timezone) return `name` in the whole process, or detect
+/// the time zone again if `name` is `None`.
+///
+/// The override applies to everything that detects the current time zone, e.g.
+/// [`detect()`](crate::detect), [`CachedTimezone`](crate::CachedTimezone) and
+/// [`LocalZone::load()`](crate::LocalZone::load), but not to a custom
+/// [`Detector`](crate::Detector), or to [`get_timezone_in_root()`](crate::get_timezone_in_root).
+/// `name` is not validated, so it may be any string, but the `posix/` and `right/` prefixes of the
+/// zoneinfo database are stripped, like they are from detected names.
+///
+/// Tests run in parallel threads by default, so all tests of the process see the override.
+/// Use [`override_timezone_local()`] to only override the time zone of the current thread.
+///
+/// The feature `testing` must be enabled.
+///
+/// ```rust
+/// iana_time_zone::set_override(Some("Asia/Kolkata"));
+/// assert_eq!(iana_time_zone::get_timezone()?, "Asia/Kolkata");
+/// iana_time_zone::set_override(None);
+/// # Ok::<(), iana_time_zone::GetTimezoneError>(())
+/// ```
+pub fn set_override(name: Option<&str>) {
+    replace_global(name.map(str::to_owned));
+}
+
+/// Override the time zone of the whole process like [`set_override()`], until the returned
+/// guard is dropped.
+///
+/// Dropping the guard restores the previous override, if there was one.
+///
+/// The feature `testing` must be enabled.
+///
+/// ```rust
+/// {
+///     let _guard = iana_time_zone::override_timezone("Europe/Berlin");
+///     assert_eq!(iana_time_zone::get_timezone()?, "Europe/Berlin");
+/// }
+/// # Ok::<(), iana_time_zone::GetTimezoneError>(())
+/// ```
+pub fn override_timezone(name: &str) -> OverrideGuard {
+    OverrideGuard {
+        previous: replace_global(Some(name.to_owned())),
+    }
+}
+
+/// Override the time zone of the current thread, until the returned guard is dropped.
+///
+/// This takes precedence over [`set_override()`]. Only functions that detect the time zone on
+/// the calling thread see the override, so e.g. `get_timezone_async()` does not, because it
+/// detects the time zone on the blocking thread pool of tokio.
+///
+/// The feature `testing` must be enabled.
+///
+/// ```rust
+/// let _guard = iana_time_zone::override_timezone_local("America/Sao_Paulo");
+/// assert_eq!(iana_time_zone::get_timezone()?, "America/Sao_Paulo");
+///
+/// // Other threads are not affected.
+/// let other = std::thread::spawn(iana_time_zone::get_timezone).join().unwrap();
+/// assert_ne!(other.ok().as_deref(), Some("America/Sao_Paulo"));
+/// # Ok::<(), iana_time_zone::GetTimezoneError>(())
+/// ```
+pub fn override_timezone_local(name: &str) -> LocalOverrideGuard {
+    let previous = LOCAL.with(|local| local.replace(Some(name.to_owned())));
+    LocalOverrideGuard {
+        previous,
+        _not_send: PhantomData,
+    }
+}
+
+/// Restores the previous time zone override of the process when dropped, see
+/// [`override_timezone()`].
+#[derive(Debug)]
+#[must_use = "the override is removed when the guard is dropped"]
+pub struct OverrideGuard {
+    previous: Option<String>,
+}
+
+impl Drop for OverrideGuard {
+    fn drop(&mut self) {
+        replace_global(self.previous.take());
+    }
+}
+
+/// Restores the previous time zone override of the thread when dropped, see
+/// [`override_timezone_local()`].
+#[derive(Debug)]
+#[must_use = "the override is removed when the guard is dropped"]
+pub struct LocalOverrideGuard {
+    previous: Option<String>,
+    /// The guard must be dropped on the thread that it overrides.
+    _not_send: PhantomData<*const ()>,
+}
+
+impl Drop for LocalOverrideGuard {
+    fn drop(&mut self) {
+        let previous = self.previous.take();
+        // The thread local storage may already be gone if the guard is dropped while the thread
+        // exits, and then there is nothing to restore.
+        let _ = LOCAL.try_with(|local| local.replace(previous));
+    }
+}
+
+fn replace_global(name: Option<String>) -> Option<String> {
+    let mut global = GLOBAL.get().write().unwrap_or_else(PoisonError::into_inner);
+    mem::replace(&mut *global, name)
+}
+
+/// The overridden time zone of the current thread, or of the process.
+pub(crate) fn overridden() -> Option<Detection> {
+    let name = LOCAL
+        .try_with(|local| local.borrow().clone())
+        .ok()
+        .flatten()
+        .or_else(|| {
+            let global = GLOBAL.get().read().unwrap_or_else(PoisonError::into_inner);
+            global.clone()
+        })?;
+    Some(Detection::new(name, Source::Override))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::override_timezone_local;
+
+    // The process-wide override would affect other tests, so only the thread-local one is tested
+    // here, and the process-wide one in the documentation tests.
+    #[test]
+    fn test_override_local() {
+        let real = crate::get_timezone().unwrap();
+        {
+            let _outer = override_timezone_local("Asia/Kolkata");
+            assert_eq!(crate::get_timezone().unwrap(), "Asia/Kolkata");
+            let detection = crate::detect().unwrap();
+            assert_eq!(detection.source, crate::Source::Override);
+            {
+                let _inner = override_timezone_local("posix/Europe/Berlin");
+                assert_eq!(crate::get_timezone().unwrap(), "Europe/Berlin");
+                assert_eq!(
+                    crate::get_timezone_cached().unwrap().as_ref(),
+                    "Europe/Berlin"
+                );
+            }
+            assert_eq!(crate::get_timezone().unwrap(), "Asia/Kolkata");
+        }
+        assert_eq!(crate::get_timezone().unwrap(), real);
+        assert_eq!(crate::get_timezone_cached().unwrap().as_ref(), real);
+    }
+}
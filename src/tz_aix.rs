@@ -1,7 +1,11 @@
-use std::env;
-use std::fs::OpenOptions;
-use std::io::{BufRead, BufReader};
+use std::path::Path;
 
-pub(crate) fn get_timezone_inner() -> Result<String, crate::GetTimezoneError> {
-    env::var("TZ").map_err(|_| crate::GetTimezoneError::OsError)
+use crate::detector::first_of;
+use crate::filesystem::StdFs;
+use crate::{BuiltinSource, Detection};
+
+pub(crate) const SOURCES: &[BuiltinSource] = crate::unix::AIX;
+
+pub(crate) fn detect_inner() -> Result<Detection, crate::GetTimezoneError> {
+    first_of(&StdFs, Path::new("/"), SOURCES)
 }
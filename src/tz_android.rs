@@ -3,13 +3,19 @@ use std::sync::Once;
 use android_system_properties::AndroidSystemProperties;
 
 use crate::ffi_utils::android_timezone_property_name;
+use crate::{Detection, Source};
 
-pub(crate) fn get_timezone_inner() -> Result<String, crate::GetTimezoneError> {
+pub(crate) const SOURCES: &[crate::BuiltinSource] = &[crate::BuiltinSource::OS_API];
+
+pub(crate) fn detect_inner() -> Result<Detection, crate::GetTimezoneError> {
     let key = android_timezone_property_name();
 
     get_properties()
         .and_then(|properties| properties.get_from_cstr(key))
-        .ok_or(crate::GetTimezoneError::OsError)
+        .map(|name| Detection::new(name, Source::OsApi))
+        .ok_or_else(|| {
+            crate::GetTimezoneError::os("the system property persist.sys.timezone", None)
+        })
 }
 
 fn get_properties() -> Option<&'static AndroidSystemProperties> {
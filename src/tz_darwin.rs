@@ -1,7 +1,12 @@
 use crate::ffi_utils::buffer::{tzname_buf, MAX_LEN};
+use crate::{Detection, Source};
 
-pub(crate) fn get_timezone_inner() -> Result<String, crate::GetTimezoneError> {
-    get_timezone().ok_or(crate::GetTimezoneError::OsError)
+pub(crate) const SOURCES: &[crate::BuiltinSource] = &[crate::BuiltinSource::OS_API];
+
+pub(crate) fn detect_inner() -> Result<Detection, crate::GetTimezoneError> {
+    get_timezone()
+        .map(|name| Detection::new(name, Source::OsApi))
+        .ok_or_else(|| crate::GetTimezoneError::os("CFTimeZoneCopySystem()", None))
 }
 
 #[inline]
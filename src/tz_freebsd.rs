@@ -1,7 +1,11 @@
-pub(crate) fn get_timezone_inner() -> Result<String, crate::GetTimezoneError> {
-    // see https://gitlab.gnome.org/GNOME/evolution-data-server/-/issues/19
-    let mut contents = std::fs::read_to_string("/var/db/zoneinfo")?;
-    // Trim to the correct length without allocating.
-    contents.truncate(contents.trim_end().len());
-    Ok(contents)
+use std::path::Path;
+
+use crate::detector::first_of;
+use crate::filesystem::StdFs;
+use crate::{BuiltinSource, Detection};
+
+pub(crate) const SOURCES: &[BuiltinSource] = crate::unix::FREEBSD;
+
+pub(crate) fn detect_inner() -> Result<Detection, crate::GetTimezoneError> {
+    first_of(&StdFs, Path::new("/"), SOURCES)
 }
@@ -1,3 +1,9 @@
-pub(crate) fn get_timezone_inner() -> Result<String, crate::GetTimezoneError> {
-    iana_time_zone_haiku::get_timezone().ok_or(crate::GetTimezoneError::OsError)
+use crate::{Detection, Source};
+
+pub(crate) const SOURCES: &[crate::BuiltinSource] = &[crate::BuiltinSource::OS_API];
+
+pub(crate) fn detect_inner() -> Result<Detection, crate::GetTimezoneError> {
+    iana_time_zone_haiku::get_timezone()
+        .map(|name| Detection::new(name, Source::OsApi))
+        .ok_or_else(|| crate::GetTimezoneError::os("BLocaleRoster::GetDefaultTimeZone()", None))
 }
@@ -1,25 +1,11 @@
-use std::fs::read_link;
+use std::path::Path;
 
-pub(crate) fn get_timezone_inner() -> Result<String, crate::GetTimezoneError> {
-    // see https://www.cyberciti.biz/faq/openbsd-time-zone-howto/
+use crate::detector::first_of;
+use crate::filesystem::StdFs;
+use crate::{BuiltinSource, Detection};
 
-    // This is a backport of the Linux implementation.
-    // NetBSDs is less than thorough how the softlink should be set up.
+pub(crate) const SOURCES: &[BuiltinSource] = crate::unix::NETBSD;
 
-    const PREFIXES: &[&str] = &[
-        "/usr/share/zoneinfo/",   // absolute path
-        "../usr/share/zoneinfo/", // relative path
-    ];
-    let mut s = read_link("/etc/localtime")?
-        .into_os_string()
-        .into_string()
-        .map_err(|_| crate::GetTimezoneError::FailedParsingString)?;
-    for &prefix in PREFIXES {
-        if s.starts_with(prefix) {
-            // Trim to the correct length without allocating.
-            s.replace_range(..prefix.len(), "");
-            return Ok(s);
-        }
-    }
-    Err(crate::GetTimezoneError::FailedParsingString)
+pub(crate) fn detect_inner() -> Result<Detection, crate::GetTimezoneError> {
+    first_of(&StdFs, Path::new("/"), SOURCES)
 }
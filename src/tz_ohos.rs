@@ -3,7 +3,7 @@
 //! - [API Reference](https://gitee.com/openharmony/docs/blob/43726785b4033887cd1a838aaaca5e255897a71e/en/application-dev/reference/apis-basic-services-kit/_time_service.md#oh_timeservice_gettimezone)
 
 use crate::ffi_utils::buffer::{tzname_buf, MAX_LEN};
-use crate::GetTimezoneError;
+use crate::{Context, Detection, GetTimezoneError, OsCode, Source};
 use std::ffi::{c_char, CStr};
 
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
@@ -30,7 +30,11 @@ fn from_bytes_until_nul(bytes: &[u8]) -> Option<&CStr> {
     Some(unsafe { CStr::from_bytes_with_nul_unchecked(&bytes[..=nul_pos]) })
 }
 
-pub(crate) fn get_timezone_inner() -> Result<String, GetTimezoneError> {
+const API: &str = "OH_TimeService_GetTimeZone()";
+
+pub(crate) const SOURCES: &[crate::BuiltinSource] = &[crate::BuiltinSource::OS_API];
+
+pub(crate) fn detect_inner() -> Result<Detection, GetTimezoneError> {
     let mut time_zone = tzname_buf();
     // SAFETY:
     // `time_zone` is a valid buffer with a length of 40 bytes.
@@ -38,10 +42,14 @@ pub(crate) fn get_timezone_inner() -> Result<String, GetTimezoneError> {
         OH_TimeService_GetTimeZone(time_zone.as_mut_ptr().cast::<c_char>(), MAX_LEN as u32 - 1)
     };
     if ret != TimeService_ErrCode::TIMESERVICE_ERR_OK {
-        return Err(GetTimezoneError::OsError);
+        let code = OsCode::TimeService(ret as u32);
+        return Err(GetTimezoneError::os(API, Some(code)));
     }
     from_bytes_until_nul(&time_zone)
         .and_then(|x| x.to_str().ok())
-        .map(|x| x.to_owned())
-        .ok_or(GetTimezoneError::OsError)
+        .map(|x| Detection::new(x.to_owned(), Source::OsApi))
+        .ok_or_else(|| GetTimezoneError::FailedParsingString {
+            context: Context::Api(API),
+            reason: "not a valid UTF-8 string".to_owned(),
+        })
 }
@@ -1,4 +1,4 @@
-use crate::GetTimezoneError;
+use crate::{Context, Detection, GetTimezoneError, Source};
 use std::ffi::CStr;
 use std::os::raw::c_char;
 use std::ptr::NonNull;
@@ -7,7 +7,11 @@ extern "C" {
     fn emscripten_run_script_string(script: *const c_char) -> *mut c_char;
 }
 
-pub(crate) fn get_timezone_inner() -> Result<String, GetTimezoneError> {
+const API: &str = "Intl.DateTimeFormat().resolvedOptions().timeZone";
+
+pub(crate) const SOURCES: &[crate::BuiltinSource] = &[crate::BuiltinSource::OS_API];
+
+pub(crate) fn detect_inner() -> Result<Detection, GetTimezoneError> {
     const SCRIPT: &CStr = {
         match CStr::from_bytes_with_nul(
             "Intl.DateTimeFormat().resolvedOptions().timeZone\0".as_bytes(),
@@ -19,12 +23,16 @@ pub(crate) fn get_timezone_inner() -> Result<String, GetTimezoneError> {
 
     unsafe {
         NonNull::new(emscripten_run_script_string(SCRIPT.as_ptr()))
-            .ok_or_else(|| GetTimezoneError::OsError)
+            .ok_or_else(|| GetTimezoneError::os(API, None))
             .and_then(|ptr| {
                 CStr::from_ptr(ptr.as_ptr())
                     .to_owned()
                     .into_string()
-                    .map_err(|_| GetTimezoneError::FailedParsingString)
+                    .map(|name| Detection::new(name, Source::OsApi))
+                    .map_err(|_| GetTimezoneError::FailedParsingString {
+                        context: Context::Api(API),
+                        reason: "not a valid UTF-8 string".to_owned(),
+                    })
             })
     }
 }
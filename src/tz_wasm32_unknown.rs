@@ -1,12 +1,19 @@
 use js_sys::{Array, Intl, Object, Reflect};
 use wasm_bindgen::JsValue;
 
-pub(crate) fn get_timezone_inner() -> Result<String, crate::GetTimezoneError> {
+use crate::{Detection, Source};
+
+pub(crate) const SOURCES: &[crate::BuiltinSource] = &[crate::BuiltinSource::OS_API];
+
+pub(crate) fn detect_inner() -> Result<Detection, crate::GetTimezoneError> {
     let intl = Intl::DateTimeFormat::new(&Array::new(), &Object::new()).resolved_options();
     Reflect::get(&intl, &JsValue::from_str("timeZone"))
         .ok()
         .and_then(|tz| tz.as_string())
-        .ok_or(crate::GetTimezoneError::OsError)
+        .map(|name| Detection::new(name, Source::OsApi))
+        .ok_or_else(|| {
+            crate::GetTimezoneError::os("Intl.DateTimeFormat().resolvedOptions().timeZone", None)
+        })
 }
 
 #[cfg(test)]
@@ -15,7 +22,7 @@ mod tests {
 
     #[wasm_bindgen_test]
     fn pass() {
-        let tz = super::get_timezone_inner().unwrap();
+        let tz = super::detect_inner().unwrap().name;
         console_log!("tz={:?}", tz);
     }
 }
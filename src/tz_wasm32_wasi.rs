@@ -1,3 +1,28 @@
-pub(crate) fn get_timezone_inner() -> Result<String, crate::GetTimezoneError> {
-    std::env::var("TZ").or_else(|_| Ok("Etc/UTC".to_owned()))
+use std::env;
+use std::path::Path;
+
+use crate::detector::first_of;
+use crate::filesystem::StdFs;
+use crate::{BuiltinSource, Context, Detection, GetTimezoneError, Source};
+
+pub(crate) const SOURCES: &[BuiltinSource] = &[BuiltinSource::TZ_ENV, BuiltinSource::DEFAULT];
+
+pub(crate) fn detect_inner() -> Result<Detection, GetTimezoneError> {
+    first_of(&StdFs, Path::new("/"), SOURCES)
+}
+
+/// Read the time zone from `TZ`. An unset or empty `TZ` means UTC, which the next source reports.
+pub(crate) fn tz_env() -> Result<Detection, GetTimezoneError> {
+    let tz = env::var("TZ").unwrap_or_default();
+    let tz = tz.strip_prefix(':').unwrap_or(&tz);
+    if tz.is_empty() {
+        return Err(GetTimezoneError::NotConfigured {
+            context: Context::EnvVar("TZ"),
+        });
+    }
+    Ok(Detection::new(tz.to_owned(), Source::EnvVar))
+}
+
+pub(crate) fn utc() -> Result<Detection, GetTimezoneError> {
+    Ok(Detection::new("Etc/UTC".to_owned(), Source::Default))
 }
@@ -3,14 +3,22 @@
 mod windows_bindings;
 use windows_bindings::Windows::Globalization::Calendar;
 
-impl From<windows_core::Error> for crate::GetTimezoneError {
-    fn from(orig: windows_core::Error) -> Self {
-        crate::GetTimezoneError::IoError(std::io::Error::new(std::io::ErrorKind::Other, orig))
-    }
+/// Wrap an error of the API `name`.
+fn windows_error(
+    name: &'static str,
+) -> impl FnOnce(windows_core::Error) -> crate::GetTimezoneError {
+    move |err| crate::GetTimezoneError::os(name, Some(crate::OsCode::HResult(err.code().0)))
 }
 
-pub(crate) fn get_timezone_inner() -> Result<String, crate::GetTimezoneError> {
-    let cal = Calendar::new()?;
-    let tz_hstring = cal.GetTimeZone()?;
-    Ok(tz_hstring.to_string())
+pub(crate) const SOURCES: &[crate::BuiltinSource] = &[crate::BuiltinSource::OS_API];
+
+pub(crate) fn detect_inner() -> Result<crate::Detection, crate::GetTimezoneError> {
+    let cal = Calendar::new().map_err(windows_error("Calendar::new()"))?;
+    let tz_hstring = cal
+        .GetTimeZone()
+        .map_err(windows_error("Calendar::GetTimeZone()"))?;
+    Ok(crate::Detection::new(
+        tz_hstring.to_string(),
+        crate::Source::OsApi,
+    ))
 }
@@ -0,0 +1,1215 @@
+// Generated by `tzdata_gen` from tzdata 2025b
+
+/// The links of the file `backward` as `(alias, target)`, sorted by alias.
+pub(crate) static LINKS: &[(&str, &str)] = &[
+    ("Africa/Accra", "Africa/Abidjan"),
+    ("Africa/Addis_Ababa", "Africa/Nairobi"),
+    ("Africa/Asmara", "Africa/Nairobi"),
+    ("Africa/Asmera", "Africa/Nairobi"),
+    ("Africa/Bamako", "Africa/Abidjan"),
+    ("Africa/Bangui", "Africa/Lagos"),
+    ("Africa/Banjul", "Africa/Abidjan"),
+    ("Africa/Blantyre", "Africa/Maputo"),
+    ("Africa/Brazzaville", "Africa/Lagos"),
+    ("Africa/Bujumbura", "Africa/Maputo"),
+    ("Africa/Conakry", "Africa/Abidjan"),
+    ("Africa/Dakar", "Africa/Abidjan"),
+    ("Africa/Dar_es_Salaam", "Africa/Nairobi"),
+    ("Africa/Djibouti", "Africa/Nairobi"),
+    ("Africa/Douala", "Africa/Lagos"),
+    ("Africa/Freetown", "Africa/Abidjan"),
+    ("Africa/Gaborone", "Africa/Maputo"),
+    ("Africa/Harare", "Africa/Maputo"),
+    ("Africa/Kampala", "Africa/Nairobi"),
+    ("Africa/Kigali", "Africa/Maputo"),
+    ("Africa/Kinshasa", "Africa/Lagos"),
+    ("Africa/Libreville", "Africa/Lagos"),
+    ("Africa/Lome", "Africa/Abidjan"),
+    ("Africa/Luanda", "Africa/Lagos"),
+    ("Africa/Lubumbashi", "Africa/Maputo"),
+    ("Africa/Lusaka", "Africa/Maputo"),
+    ("Africa/Malabo", "Africa/Lagos"),
+    ("Africa/Maseru", "Africa/Johannesburg"),
+    ("Africa/Mbabane", "Africa/Johannesburg"),
+    ("Africa/Mogadishu", "Africa/Nairobi"),
+    ("Africa/Niamey", "Africa/Lagos"),
+    ("Africa/Nouakchott", "Africa/Abidjan"),
+    ("Africa/Ouagadougou", "Africa/Abidjan"),
+    ("Africa/Porto-Novo", "Africa/Lagos"),
+    ("Africa/Timbuktu", "Africa/Abidjan"),
+    ("America/Anguilla", "America/Puerto_Rico"),
+    ("America/Antigua", "America/Puerto_Rico"),
+    (
+        "America/Argentina/ComodRivadavia",
+        "America/Argentina/Catamarca",
+    ),
+    ("America/Aruba", "America/Puerto_Rico"),
+    ("America/Atikokan", "America/Panama"),
+    ("America/Atka", "America/Adak"),
+    ("America/Blanc-Sablon", "America/Puerto_Rico"),
+    ("America/Buenos_Aires", "America/Argentina/Buenos_Aires"),
+    ("America/Catamarca", "America/Argentina/Catamarca"),
+    ("America/Cayman", "America/Panama"),
+    ("America/Coral_Harbour", "America/Panama"),
+    ("America/Cordoba", "America/Argentina/Cordoba"),
+    ("America/Creston", "America/Phoenix"),
+    ("America/Curacao", "America/Puerto_Rico"),
+    ("America/Dominica", "America/Puerto_Rico"),
+    ("America/Ensenada", "America/Tijuana"),
+    ("America/Fort_Wayne", "America/Indiana/Indianapolis"),
+    ("America/Godthab", "America/Nuuk"),
+    ("America/Grenada", "America/Puerto_Rico"),
+    ("America/Guadeloupe", "America/Puerto_Rico"),
+    ("America/Indianapolis", "America/Indiana/Indianapolis"),
+    ("America/Jujuy", "America/Argentina/Jujuy"),
+    ("America/Knox_IN", "America/Indiana/Knox"),
+    ("America/Kralendijk", "America/Puerto_Rico"),
+    ("America/Louisville", "America/Kentucky/Louisville"),
+    ("America/Lower_Princes", "America/Puerto_Rico"),
+    ("America/Marigot", "America/Puerto_Rico"),
+    ("America/Mendoza", "America/Argentina/Mendoza"),
+    ("America/Montreal", "America/Toronto"),
+    ("America/Montserrat", "America/Puerto_Rico"),
+    ("America/Nassau", "America/Toronto"),
+    ("America/Nipigon", "America/Toronto"),
+    ("America/Pangnirtung", "America/Iqaluit"),
+    ("America/Port_of_Spain", "America/Puerto_Rico"),
+    ("America/Porto_Acre", "America/Rio_Branco"),
+    ("America/Rainy_River", "America/Winnipeg"),
+    ("America/Rosario", "America/Argentina/Cordoba"),
+    ("America/Santa_Isabel", "America/Tijuana"),
+    ("America/Shiprock", "America/Denver"),
+    ("America/St_Barthelemy", "America/Puerto_Rico"),
+    ("America/St_Kitts", "America/Puerto_Rico"),
+    ("America/St_Lucia", "America/Puerto_Rico"),
+    ("America/St_Thomas", "America/Puerto_Rico"),
+    ("America/St_Vincent", "America/Puerto_Rico"),
+    ("America/Thunder_Bay", "America/Toronto"),
+    ("America/Tortola", "America/Puerto_Rico"),
+    ("America/Virgin", "America/Puerto_Rico"),
+    ("America/Yellowknife", "America/Edmonton"),
+    ("Antarctica/DumontDUrville", "Pacific/Port_Moresby"),
+    ("Antarctica/McMurdo", "Pacific/Auckland"),
+    ("Antarctica/South_Pole", "Pacific/Auckland"),
+    ("Antarctica/Syowa", "Asia/Riyadh"),
+    ("Arctic/Longyearbyen", "Europe/Berlin"),
+    ("Asia/Aden", "Asia/Riyadh"),
+    ("Asia/Ashkhabad", "Asia/Ashgabat"),
+    ("Asia/Bahrain", "Asia/Qatar"),
+    ("Asia/Brunei", "Asia/Kuching"),
+    ("Asia/Calcutta", "Asia/Kolkata"),
+    ("Asia/Choibalsan", "Asia/Ulaanbaatar"),
+    ("Asia/Chongqing", "Asia/Shanghai"),
+    ("Asia/Chungking", "Asia/Shanghai"),
+    ("Asia/Dacca", "Asia/Dhaka"),
+    ("Asia/Harbin", "Asia/Shanghai"),
+    ("Asia/Istanbul", "Europe/Istanbul"),
+    ("Asia/Kashgar", "Asia/Urumqi"),
+    ("Asia/Katmandu", "Asia/Kathmandu"),
+    ("Asia/Kuala_Lumpur", "Asia/Singapore"),
+    ("Asia/Kuwait", "Asia/Riyadh"),
+    ("Asia/Macao", "Asia/Macau"),
+    ("Asia/Muscat", "Asia/Dubai"),
+    ("Asia/Phnom_Penh", "Asia/Bangkok"),
+    ("Asia/Rangoon", "Asia/Yangon"),
+    ("Asia/Saigon", "Asia/Ho_Chi_Minh"),
+    ("Asia/Tel_Aviv", "Asia/Jerusalem"),
+    ("Asia/Thimbu", "Asia/Thimphu"),
+    ("Asia/Ujung_Pandang", "Asia/Makassar"),
+    ("Asia/Ulan_Bator", "Asia/Ulaanbaatar"),
+    ("Asia/Vientiane", "Asia/Bangkok"),
+    ("Atlantic/Faeroe", "Atlantic/Faroe"),
+    ("Atlantic/Jan_Mayen", "Europe/Berlin"),
+    ("Atlantic/Reykjavik", "Africa/Abidjan"),
+    ("Atlantic/St_Helena", "Africa/Abidjan"),
+    ("Australia/ACT", "Australia/Sydney"),
+    ("Australia/Canberra", "Australia/Sydney"),
+    ("Australia/Currie", "Australia/Hobart"),
+    ("Australia/LHI", "Australia/Lord_Howe"),
+    ("Australia/NSW", "Australia/Sydney"),
+    ("Australia/North", "Australia/Darwin"),
+    ("Australia/Queensland", "Australia/Brisbane"),
+    ("Australia/South", "Australia/Adelaide"),
+    ("Australia/Tasmania", "Australia/Hobart"),
+    ("Australia/Victoria", "Australia/Melbourne"),
+    ("Australia/West", "Australia/Perth"),
+    ("Australia/Yancowinna", "Australia/Broken_Hill"),
+    ("Brazil/Acre", "America/Rio_Branco"),
+    ("Brazil/DeNoronha", "America/Noronha"),
+    ("Brazil/East", "America/Sao_Paulo"),
+    ("Brazil/West", "America/Manaus"),
+    ("CET", "Europe/Brussels"),
+    ("CST6CDT", "America/Chicago"),
+    ("Canada/Atlantic", "America/Halifax"),
+    ("Canada/Central", "America/Winnipeg"),
+    ("Canada/Eastern", "America/Toronto"),
+    ("Canada/Mountain", "America/Edmonton"),
+    ("Canada/Newfoundland", "America/St_Johns"),
+    ("Canada/Pacific", "America/Vancouver"),
+    ("Canada/Saskatchewan", "America/Regina"),
+    ("Canada/Yukon", "America/Whitehorse"),
+    ("Chile/Continental", "America/Santiago"),
+    ("Chile/EasterIsland", "Pacific/Easter"),
+    ("Cuba", "America/Havana"),
+    ("EET", "Europe/Athens"),
+    ("EST", "America/Panama"),
+    ("EST5EDT", "America/New_York"),
+    ("Egypt", "Africa/Cairo"),
+    ("Eire", "Europe/Dublin"),
+    ("Etc/GMT+0", "Etc/GMT"),
+    ("Etc/GMT-0", "Etc/GMT"),
+    ("Etc/GMT0", "Etc/GMT"),
+    ("Etc/Greenwich", "Etc/GMT"),
+    ("Etc/UCT", "Etc/UTC"),
+    ("Etc/Universal", "Etc/UTC"),
+    ("Etc/Zulu", "Etc/UTC"),
+    ("Europe/Amsterdam", "Europe/Brussels"),
+    ("Europe/Belfast", "Europe/London"),
+    ("Europe/Bratislava", "Europe/Prague"),
+    ("Europe/Busingen", "Europe/Zurich"),
+    ("Europe/Copenhagen", "Europe/Berlin"),
+    ("Europe/Guernsey", "Europe/London"),
+    ("Europe/Isle_of_Man", "Europe/London"),
+    ("Europe/Jersey", "Europe/London"),
+    ("Europe/Kiev", "Europe/Kyiv"),
+    ("Europe/Ljubljana", "Europe/Belgrade"),
+    ("Europe/Luxembourg", "Europe/Brussels"),
+    ("Europe/Mariehamn", "Europe/Helsinki"),
+    ("Europe/Monaco", "Europe/Paris"),
+    ("Europe/Nicosia", "Asia/Nicosia"),
+    ("Europe/Oslo", "Europe/Berlin"),
+    ("Europe/Podgorica", "Europe/Belgrade"),
+    ("Europe/San_Marino", "Europe/Rome"),
+    ("Europe/Sarajevo", "Europe/Belgrade"),
+    ("Europe/Skopje", "Europe/Belgrade"),
+    ("Europe/Stockholm", "Europe/Berlin"),
+    ("Europe/Tiraspol", "Europe/Chisinau"),
+    ("Europe/Uzhgorod", "Europe/Kyiv"),
+    ("Europe/Vaduz", "Europe/Zurich"),
+    ("Europe/Vatican", "Europe/Rome"),
+    ("Europe/Zagreb", "Europe/Belgrade"),
+    ("Europe/Zaporozhye", "Europe/Kyiv"),
+    ("GB", "Europe/London"),
+    ("GB-Eire", "Europe/London"),
+    ("GMT+0", "Etc/GMT"),
+    ("GMT-0", "Etc/GMT"),
+    ("GMT0", "Etc/GMT"),
+    ("Greenwich", "Etc/GMT"),
+    ("HST", "Pacific/Honolulu"),
+    ("Hongkong", "Asia/Hong_Kong"),
+    ("Iceland", "Africa/Abidjan"),
+    ("Indian/Antananarivo", "Africa/Nairobi"),
+    ("Indian/Christmas", "Asia/Bangkok"),
+    ("Indian/Cocos", "Asia/Yangon"),
+    ("Indian/Comoro", "Africa/Nairobi"),
+    ("Indian/Kerguelen", "Indian/Maldives"),
+    ("Indian/Mahe", "Asia/Dubai"),
+    ("Indian/Mayotte", "Africa/Nairobi"),
+    ("Indian/Reunion", "Asia/Dubai"),
+    ("Iran", "Asia/Tehran"),
+    ("Israel", "Asia/Jerusalem"),
+    ("Jamaica", "America/Jamaica"),
+    ("Japan", "Asia/Tokyo"),
+    ("Kwajalein", "Pacific/Kwajalein"),
+    ("Libya", "Africa/Tripoli"),
+    ("MET", "Europe/Brussels"),
+    ("MST", "America/Phoenix"),
+    ("MST7MDT", "America/Denver"),
+    ("Mexico/BajaNorte", "America/Tijuana"),
+    ("Mexico/BajaSur", "America/Mazatlan"),
+    ("Mexico/General", "America/Mexico_City"),
+    ("NZ", "Pacific/Auckland"),
+    ("NZ-CHAT", "Pacific/Chatham"),
+    ("Navajo", "America/Denver"),
+    ("PRC", "Asia/Shanghai"),
+    ("PST8PDT", "America/Los_Angeles"),
+    ("Pacific/Chuuk", "Pacific/Port_Moresby"),
+    ("Pacific/Enderbury", "Pacific/Kanton"),
+    ("Pacific/Funafuti", "Pacific/Tarawa"),
+    ("Pacific/Johnston", "Pacific/Honolulu"),
+    ("Pacific/Majuro", "Pacific/Tarawa"),
+    ("Pacific/Midway", "Pacific/Pago_Pago"),
+    ("Pacific/Pohnpei", "Pacific/Guadalcanal"),
+    ("Pacific/Ponape", "Pacific/Guadalcanal"),
+    ("Pacific/Saipan", "Pacific/Guam"),
+    ("Pacific/Samoa", "Pacific/Pago_Pago"),
+    ("Pacific/Truk", "Pacific/Port_Moresby"),
+    ("Pacific/Wake", "Pacific/Tarawa"),
+    ("Pacific/Wallis", "Pacific/Tarawa"),
+    ("Pacific/Yap", "Pacific/Port_Moresby"),
+    ("Poland", "Europe/Warsaw"),
+    ("Portugal", "Europe/Lisbon"),
+    ("ROC", "Asia/Taipei"),
+    ("ROK", "Asia/Seoul"),
+    ("Singapore", "Asia/Singapore"),
+    ("Turkey", "Europe/Istanbul"),
+    ("UCT", "Etc/UTC"),
+    ("US/Alaska", "America/Anchorage"),
+    ("US/Aleutian", "America/Adak"),
+    ("US/Arizona", "America/Phoenix"),
+    ("US/Central", "America/Chicago"),
+    ("US/East-Indiana", "America/Indiana/Indianapolis"),
+    ("US/Eastern", "America/New_York"),
+    ("US/Hawaii", "Pacific/Honolulu"),
+    ("US/Indiana-Starke", "America/Indiana/Knox"),
+    ("US/Michigan", "America/Detroit"),
+    ("US/Mountain", "America/Denver"),
+    ("US/Pacific", "America/Los_Angeles"),
+    ("US/Samoa", "Pacific/Pago_Pago"),
+    ("UTC", "Etc/UTC"),
+    ("Universal", "Etc/UTC"),
+    ("W-SU", "Europe/Moscow"),
+    ("WET", "Europe/Lisbon"),
+    ("Zulu", "Etc/UTC"),
+];
+
+/// The names of all zones and links, sorted.
+pub(crate) static NAMES: &[&str] = &[
+    "Africa/Abidjan",
+    "Africa/Accra",
+    "Africa/Addis_Ababa",
+    "Africa/Algiers",
+    "Africa/Asmara",
+    "Africa/Asmera",
+    "Africa/Bamako",
+    "Africa/Bangui",
+    "Africa/Banjul",
+    "Africa/Bissau",
+    "Africa/Blantyre",
+    "Africa/Brazzaville",
+    "Africa/Bujumbura",
+    "Africa/Cairo",
+    "Africa/Casablanca",
+    "Africa/Ceuta",
+    "Africa/Conakry",
+    "Africa/Dakar",
+    "Africa/Dar_es_Salaam",
+    "Africa/Djibouti",
+    "Africa/Douala",
+    "Africa/El_Aaiun",
+    "Africa/Freetown",
+    "Africa/Gaborone",
+    "Africa/Harare",
+    "Africa/Johannesburg",
+    "Africa/Juba",
+    "Africa/Kampala",
+    "Africa/Khartoum",
+    "Africa/Kigali",
+    "Africa/Kinshasa",
+    "Africa/Lagos",
+    "Africa/Libreville",
+    "Africa/Lome",
+    "Africa/Luanda",
+    "Africa/Lubumbashi",
+    "Africa/Lusaka",
+    "Africa/Malabo",
+    "Africa/Maputo",
+    "Africa/Maseru",
+    "Africa/Mbabane",
+    "Africa/Mogadishu",
+    "Africa/Monrovia",
+    "Africa/Nairobi",
+    "Africa/Ndjamena",
+    "Africa/Niamey",
+    "Africa/Nouakchott",
+    "Africa/Ouagadougou",
+    "Africa/Porto-Novo",
+    "Africa/Sao_Tome",
+    "Africa/Timbuktu",
+    "Africa/Tripoli",
+    "Africa/Tunis",
+    "Africa/Windhoek",
+    "America/Adak",
+    "America/Anchorage",
+    "America/Anguilla",
+    "America/Antigua",
+    "America/Araguaina",
+    "America/Argentina/Buenos_Aires",
+    "America/Argentina/Catamarca",
+    "America/Argentina/ComodRivadavia",
+    "America/Argentina/Cordoba",
+    "America/Argentina/Jujuy",
+    "America/Argentina/La_Rioja",
+    "America/Argentina/Mendoza",
+    "America/Argentina/Rio_Gallegos",
+    "America/Argentina/Salta",
+    "America/Argentina/San_Juan",
+    "America/Argentina/San_Luis",
+    "America/Argentina/Tucuman",
+    "America/Argentina/Ushuaia",
+    "America/Aruba",
+    "America/Asuncion",
+    "America/Atikokan",
+    "America/Atka",
+    "America/Bahia",
+    "America/Bahia_Banderas",
+    "America/Barbados",
+    "America/Belem",
+    "America/Belize",
+    "America/Blanc-Sablon",
+    "America/Boa_Vista",
+    "America/Bogota",
+    "America/Boise",
+    "America/Buenos_Aires",
+    "America/Cambridge_Bay",
+    "America/Campo_Grande",
+    "America/Cancun",
+    "America/Caracas",
+    "America/Catamarca",
+    "America/Cayenne",
+    "America/Cayman",
+    "America/Chicago",
+    "America/Chihuahua",
+    "America/Ciudad_Juarez",
+    "America/Coral_Harbour",
+    "America/Cordoba",
+    "America/Costa_Rica",
+    "America/Coyhaique",
+    "America/Creston",
+    "America/Cuiaba",
+    "America/Curacao",
+    "America/Danmarkshavn",
+    "America/Dawson",
+    "America/Dawson_Creek",
+    "America/Denver",
+    "America/Detroit",
+    "America/Dominica",
+    "America/Edmonton",
+    "America/Eirunepe",
+    "America/El_Salvador",
+    "America/Ensenada",
+    "America/Fort_Nelson",
+    "America/Fort_Wayne",
+    "America/Fortaleza",
+    "America/Glace_Bay",
+    "America/Godthab",
+    "America/Goose_Bay",
+    "America/Grand_Turk",
+    "America/Grenada",
+    "America/Guadeloupe",
+    "America/Guatemala",
+    "America/Guayaquil",
+    "America/Guyana",
+    "America/Halifax",
+    "America/Havana",
+    "America/Hermosillo",
+    "America/Indiana/Indianapolis",
+    "America/Indiana/Knox",
+    "America/Indiana/Marengo",
+    "America/Indiana/Petersburg",
+    "America/Indiana/Tell_City",
+    "America/Indiana/Vevay",
+    "America/Indiana/Vincennes",
+    "America/Indiana/Winamac",
+    "America/Indianapolis",
+    "America/Inuvik",
+    "America/Iqaluit",
+    "America/Jamaica",
+    "America/Jujuy",
+    "America/Juneau",
+    "America/Kentucky/Louisville",
+    "America/Kentucky/Monticello",
+    "America/Knox_IN",
+    "America/Kralendijk",
+    "America/La_Paz",
+    "America/Lima",
+    "America/Los_Angeles",
+    "America/Louisville",
+    "America/Lower_Princes",
+    "America/Maceio",
+    "America/Managua",
+    "America/Manaus",
+    "America/Marigot",
+    "America/Martinique",
+    "America/Matamoros",
+    "America/Mazatlan",
+    "America/Mendoza",
+    "America/Menominee",
+    "America/Merida",
+    "America/Metlakatla",
+    "America/Mexico_City",
+    "America/Miquelon",
+    "America/Moncton",
+    "America/Monterrey",
+    "America/Montevideo",
+    "America/Montreal",
+    "America/Montserrat",
+    "America/Nassau",
+    "America/New_York",
+    "America/Nipigon",
+    "America/Nome",
+    "America/Noronha",
+    "America/North_Dakota/Beulah",
+    "America/North_Dakota/Center",
+    "America/North_Dakota/New_Salem",
+    "America/Nuuk",
+    "America/Ojinaga",
+    "America/Panama",
+    "America/Pangnirtung",
+    "America/Paramaribo",
+    "America/Phoenix",
+    "America/Port-au-Prince",
+    "America/Port_of_Spain",
+    "America/Porto_Acre",
+    "America/Porto_Velho",
+    "America/Puerto_Rico",
+    "America/Punta_Arenas",
+    "America/Rainy_River",
+    "America/Rankin_Inlet",
+    "America/Recife",
+    "America/Regina",
+    "America/Resolute",
+    "America/Rio_Branco",
+    "America/Rosario",
+    "America/Santa_Isabel",
+    "America/Santarem",
+    "America/Santiago",
+    "America/Santo_Domingo",
+    "America/Sao_Paulo",
+    "America/Scoresbysund",
+    "America/Shiprock",
+    "America/Sitka",
+    "America/St_Barthelemy",
+    "America/St_Johns",
+    "America/St_Kitts",
+    "America/St_Lucia",
+    "America/St_Thomas",
+    "America/St_Vincent",
+    "America/Swift_Current",
+    "America/Tegucigalpa",
+    "America/Thule",
+    "America/Thunder_Bay",
+    "America/Tijuana",
+    "America/Toronto",
+    "America/Tortola",
+    "America/Vancouver",
+    "America/Virgin",
+    "America/Whitehorse",
+    "America/Winnipeg",
+    "America/Yakutat",
+    "America/Yellowknife",
+    "Antarctica/Casey",
+    "Antarctica/Davis",
+    "Antarctica/DumontDUrville",
+    "Antarctica/Macquarie",
+    "Antarctica/Mawson",
+    "Antarctica/McMurdo",
+    "Antarctica/Palmer",
+    "Antarctica/Rothera",
+    "Antarctica/South_Pole",
+    "Antarctica/Syowa",
+    "Antarctica/Troll",
+    "Antarctica/Vostok",
+    "Arctic/Longyearbyen",
+    "Asia/Aden",
+    "Asia/Almaty",
+    "Asia/Amman",
+    "Asia/Anadyr",
+    "Asia/Aqtau",
+    "Asia/Aqtobe",
+    "Asia/Ashgabat",
+    "Asia/Ashkhabad",
+    "Asia/Atyrau",
+    "Asia/Baghdad",
+    "Asia/Bahrain",
+    "Asia/Baku",
+    "Asia/Bangkok",
+    "Asia/Barnaul",
+    "Asia/Beirut",
+    "Asia/Bishkek",
+    "Asia/Brunei",
+    "Asia/Calcutta",
+    "Asia/Chita",
+    "Asia/Choibalsan",
+    "Asia/Chongqing",
+    "Asia/Chungking",
+    "Asia/Colombo",
+    "Asia/Dacca",
+    "Asia/Damascus",
+    "Asia/Dhaka",
+    "Asia/Dili",
+    "Asia/Dubai",
+    "Asia/Dushanbe",
+    "Asia/Famagusta",
+    "Asia/Gaza",
+    "Asia/Harbin",
+    "Asia/Hebron",
+    "Asia/Ho_Chi_Minh",
+    "Asia/Hong_Kong",
+    "Asia/Hovd",
+    "Asia/Irkutsk",
+    "Asia/Istanbul",
+    "Asia/Jakarta",
+    "Asia/Jayapura",
+    "Asia/Jerusalem",
+    "Asia/Kabul",
+    "Asia/Kamchatka",
+    "Asia/Karachi",
+    "Asia/Kashgar",
+    "Asia/Kathmandu",
+    "Asia/Katmandu",
+    "Asia/Khandyga",
+    "Asia/Kolkata",
+    "Asia/Krasnoyarsk",
+    "Asia/Kuala_Lumpur",
+    "Asia/Kuching",
+    "Asia/Kuwait",
+    "Asia/Macao",
+    "Asia/Macau",
+    "Asia/Magadan",
+    "Asia/Makassar",
+    "Asia/Manila",
+    "Asia/Muscat",
+    "Asia/Nicosia",
+    "Asia/Novokuznetsk",
+    "Asia/Novosibirsk",
+    "Asia/Omsk",
+    "Asia/Oral",
+    "Asia/Phnom_Penh",
+    "Asia/Pontianak",
+    "Asia/Pyongyang",
+    "Asia/Qatar",
+    "Asia/Qostanay",
+    "Asia/Qyzylorda",
+    "Asia/Rangoon",
+    "Asia/Riyadh",
+    "Asia/Saigon",
+    "Asia/Sakhalin",
+    "Asia/Samarkand",
+    "Asia/Seoul",
+    "Asia/Shanghai",
+    "Asia/Singapore",
+    "Asia/Srednekolymsk",
+    "Asia/Taipei",
+    "Asia/Tashkent",
+    "Asia/Tbilisi",
+    "Asia/Tehran",
+    "Asia/Tel_Aviv",
+    "Asia/Thimbu",
+    "Asia/Thimphu",
+    "Asia/Tokyo",
+    "Asia/Tomsk",
+    "Asia/Ujung_Pandang",
+    "Asia/Ulaanbaatar",
+    "Asia/Ulan_Bator",
+    "Asia/Urumqi",
+    "Asia/Ust-Nera",
+    "Asia/Vientiane",
+    "Asia/Vladivostok",
+    "Asia/Yakutsk",
+    "Asia/Yangon",
+    "Asia/Yekaterinburg",
+    "Asia/Yerevan",
+    "Atlantic/Azores",
+    "Atlantic/Bermuda",
+    "Atlantic/Canary",
+    "Atlantic/Cape_Verde",
+    "Atlantic/Faeroe",
+    "Atlantic/Faroe",
+    "Atlantic/Jan_Mayen",
+    "Atlantic/Madeira",
+    "Atlantic/Reykjavik",
+    "Atlantic/South_Georgia",
+    "Atlantic/St_Helena",
+    "Atlantic/Stanley",
+    "Australia/ACT",
+    "Australia/Adelaide",
+    "Australia/Brisbane",
+    "Australia/Broken_Hill",
+    "Australia/Canberra",
+    "Australia/Currie",
+    "Australia/Darwin",
+    "Australia/Eucla",
+    "Australia/Hobart",
+    "Australia/LHI",
+    "Australia/Lindeman",
+    "Australia/Lord_Howe",
+    "Australia/Melbourne",
+    "Australia/NSW",
+    "Australia/North",
+    "Australia/Perth",
+    "Australia/Queensland",
+    "Australia/South",
+    "Australia/Sydney",
+    "Australia/Tasmania",
+    "Australia/Victoria",
+    "Australia/West",
+    "Australia/Yancowinna",
+    "Brazil/Acre",
+    "Brazil/DeNoronha",
+    "Brazil/East",
+    "Brazil/West",
+    "CET",
+    "CST6CDT",
+    "Canada/Atlantic",
+    "Canada/Central",
+    "Canada/Eastern",
+    "Canada/Mountain",
+    "Canada/Newfoundland",
+    "Canada/Pacific",
+    "Canada/Saskatchewan",
+    "Canada/Yukon",
+    "Chile/Continental",
+    "Chile/EasterIsland",
+    "Cuba",
+    "EET",
+    "EST",
+    "EST5EDT",
+    "Egypt",
+    "Eire",
+    "Etc/GMT",
+    "Etc/GMT+0",
+    "Etc/GMT+1",
+    "Etc/GMT+10",
+    "Etc/GMT+11",
+    "Etc/GMT+12",
+    "Etc/GMT+2",
+    "Etc/GMT+3",
+    "Etc/GMT+4",
+    "Etc/GMT+5",
+    "Etc/GMT+6",
+    "Etc/GMT+7",
+    "Etc/GMT+8",
+    "Etc/GMT+9",
+    "Etc/GMT-0",
+    "Etc/GMT-1",
+    "Etc/GMT-10",
+    "Etc/GMT-11",
+    "Etc/GMT-12",
+    "Etc/GMT-13",
+    "Etc/GMT-14",
+    "Etc/GMT-2",
+    "Etc/GMT-3",
+    "Etc/GMT-4",
+    "Etc/GMT-5",
+    "Etc/GMT-6",
+    "Etc/GMT-7",
+    "Etc/GMT-8",
+    "Etc/GMT-9",
+    "Etc/GMT0",
+    "Etc/Greenwich",
+    "Etc/UCT",
+    "Etc/UTC",
+    "Etc/Universal",
+    "Etc/Zulu",
+    "Europe/Amsterdam",
+    "Europe/Andorra",
+    "Europe/Astrakhan",
+    "Europe/Athens",
+    "Europe/Belfast",
+    "Europe/Belgrade",
+    "Europe/Berlin",
+    "Europe/Bratislava",
+    "Europe/Brussels",
+    "Europe/Bucharest",
+    "Europe/Budapest",
+    "Europe/Busingen",
+    "Europe/Chisinau",
+    "Europe/Copenhagen",
+    "Europe/Dublin",
+    "Europe/Gibraltar",
+    "Europe/Guernsey",
+    "Europe/Helsinki",
+    "Europe/Isle_of_Man",
+    "Europe/Istanbul",
+    "Europe/Jersey",
+    "Europe/Kaliningrad",
+    "Europe/Kiev",
+    "Europe/Kirov",
+    "Europe/Kyiv",
+    "Europe/Lisbon",
+    "Europe/Ljubljana",
+    "Europe/London",
+    "Europe/Luxembourg",
+    "Europe/Madrid",
+    "Europe/Malta",
+    "Europe/Mariehamn",
+    "Europe/Minsk",
+    "Europe/Monaco",
+    "Europe/Moscow",
+    "Europe/Nicosia",
+    "Europe/Oslo",
+    "Europe/Paris",
+    "Europe/Podgorica",
+    "Europe/Prague",
+    "Europe/Riga",
+    "Europe/Rome",
+    "Europe/Samara",
+    "Europe/San_Marino",
+    "Europe/Sarajevo",
+    "Europe/Saratov",
+    "Europe/Simferopol",
+    "Europe/Skopje",
+    "Europe/Sofia",
+    "Europe/Stockholm",
+    "Europe/Tallinn",
+    "Europe/Tirane",
+    "Europe/Tiraspol",
+    "Europe/Ulyanovsk",
+    "Europe/Uzhgorod",
+    "Europe/Vaduz",
+    "Europe/Vatican",
+    "Europe/Vienna",
+    "Europe/Vilnius",
+    "Europe/Volgograd",
+    "Europe/Warsaw",
+    "Europe/Zagreb",
+    "Europe/Zaporozhye",
+    "Europe/Zurich",
+    "GB",
+    "GB-Eire",
+    "GMT",
+    "GMT+0",
+    "GMT-0",
+    "GMT0",
+    "Greenwich",
+    "HST",
+    "Hongkong",
+    "Iceland",
+    "Indian/Antananarivo",
+    "Indian/Chagos",
+    "Indian/Christmas",
+    "Indian/Cocos",
+    "Indian/Comoro",
+    "Indian/Kerguelen",
+    "Indian/Mahe",
+    "Indian/Maldives",
+    "Indian/Mauritius",
+    "Indian/Mayotte",
+    "Indian/Reunion",
+    "Iran",
+    "Israel",
+    "Jamaica",
+    "Japan",
+    "Kwajalein",
+    "Libya",
+    "MET",
+    "MST",
+    "MST7MDT",
+    "Mexico/BajaNorte",
+    "Mexico/BajaSur",
+    "Mexico/General",
+    "NZ",
+    "NZ-CHAT",
+    "Navajo",
+    "PRC",
+    "PST8PDT",
+    "Pacific/Apia",
+    "Pacific/Auckland",
+    "Pacific/Bougainville",
+    "Pacific/Chatham",
+    "Pacific/Chuuk",
+    "Pacific/Easter",
+    "Pacific/Efate",
+    "Pacific/Enderbury",
+    "Pacific/Fakaofo",
+    "Pacific/Fiji",
+    "Pacific/Funafuti",
+    "Pacific/Galapagos",
+    "Pacific/Gambier",
+    "Pacific/Guadalcanal",
+    "Pacific/Guam",
+    "Pacific/Honolulu",
+    "Pacific/Johnston",
+    "Pacific/Kanton",
+    "Pacific/Kiritimati",
+    "Pacific/Kosrae",
+    "Pacific/Kwajalein",
+    "Pacific/Majuro",
+    "Pacific/Marquesas",
+    "Pacific/Midway",
+    "Pacific/Nauru",
+    "Pacific/Niue",
+    "Pacific/Norfolk",
+    "Pacific/Noumea",
+    "Pacific/Pago_Pago",
+    "Pacific/Palau",
+    "Pacific/Pitcairn",
+    "Pacific/Pohnpei",
+    "Pacific/Ponape",
+    "Pacific/Port_Moresby",
+    "Pacific/Rarotonga",
+    "Pacific/Saipan",
+    "Pacific/Samoa",
+    "Pacific/Tahiti",
+    "Pacific/Tarawa",
+    "Pacific/Tongatapu",
+    "Pacific/Truk",
+    "Pacific/Wake",
+    "Pacific/Wallis",
+    "Pacific/Yap",
+    "Poland",
+    "Portugal",
+    "ROC",
+    "ROK",
+    "Singapore",
+    "Turkey",
+    "UCT",
+    "US/Alaska",
+    "US/Aleutian",
+    "US/Arizona",
+    "US/Central",
+    "US/East-Indiana",
+    "US/Eastern",
+    "US/Hawaii",
+    "US/Indiana-Starke",
+    "US/Michigan",
+    "US/Mountain",
+    "US/Pacific",
+    "US/Samoa",
+    "UTC",
+    "Universal",
+    "W-SU",
+    "WET",
+    "Zulu",
+];
+
+/// The POSIX TZ rule strings of the current rules of all zones, sorted by zone.
+pub(crate) static POSIX_TZ: &[(&str, &str)] = &[
+    ("Africa/Abidjan", "GMT0"),
+    ("Africa/Algiers", "CET-1"),
+    ("Africa/Bissau", "GMT0"),
+    ("Africa/Cairo", "EET-2EEST,M4.5.5/0,M10.5.4/24"),
+    ("Africa/Casablanca", "<+01>-1"),
+    ("Africa/Ceuta", "CET-1CEST,M3.5.0,M10.5.0/3"),
+    ("Africa/El_Aaiun", "<+01>-1"),
+    ("Africa/Johannesburg", "SAST-2"),
+    ("Africa/Juba", "CAT-2"),
+    ("Africa/Khartoum", "CAT-2"),
+    ("Africa/Lagos", "WAT-1"),
+    ("Africa/Maputo", "CAT-2"),
+    ("Africa/Monrovia", "GMT0"),
+    ("Africa/Nairobi", "EAT-3"),
+    ("Africa/Ndjamena", "WAT-1"),
+    ("Africa/Sao_Tome", "GMT0"),
+    ("Africa/Tripoli", "EET-2"),
+    ("Africa/Tunis", "CET-1"),
+    ("Africa/Windhoek", "CAT-2"),
+    ("America/Adak", "HST10HDT,M3.2.0,M11.1.0"),
+    ("America/Anchorage", "AKST9AKDT,M3.2.0,M11.1.0"),
+    ("America/Araguaina", "<-03>3"),
+    ("America/Argentina/Buenos_Aires", "<-03>3"),
+    ("America/Argentina/Catamarca", "<-03>3"),
+    ("America/Argentina/Cordoba", "<-03>3"),
+    ("America/Argentina/Jujuy", "<-03>3"),
+    ("America/Argentina/La_Rioja", "<-03>3"),
+    ("America/Argentina/Mendoza", "<-03>3"),
+    ("America/Argentina/Rio_Gallegos", "<-03>3"),
+    ("America/Argentina/Salta", "<-03>3"),
+    ("America/Argentina/San_Juan", "<-03>3"),
+    ("America/Argentina/San_Luis", "<-03>3"),
+    ("America/Argentina/Tucuman", "<-03>3"),
+    ("America/Argentina/Ushuaia", "<-03>3"),
+    ("America/Asuncion", "<-03>3"),
+    ("America/Bahia", "<-03>3"),
+    ("America/Bahia_Banderas", "CST6"),
+    ("America/Barbados", "AST4"),
+    ("America/Belem", "<-03>3"),
+    ("America/Belize", "CST6"),
+    ("America/Boa_Vista", "<-04>4"),
+    ("America/Bogota", "<-05>5"),
+    ("America/Boise", "MST7MDT,M3.2.0,M11.1.0"),
+    ("America/Cambridge_Bay", "MST7MDT,M3.2.0,M11.1.0"),
+    ("America/Campo_Grande", "<-04>4"),
+    ("America/Cancun", "EST5"),
+    ("America/Caracas", "<-04>4"),
+    ("America/Cayenne", "<-03>3"),
+    ("America/Chicago", "CST6CDT,M3.2.0,M11.1.0"),
+    ("America/Chihuahua", "CST6"),
+    ("America/Ciudad_Juarez", "MST7MDT,M3.2.0,M11.1.0"),
+    ("America/Costa_Rica", "CST6"),
+    ("America/Coyhaique", "<-03>3"),
+    ("America/Cuiaba", "<-04>4"),
+    ("America/Danmarkshavn", "GMT0"),
+    ("America/Dawson", "MST7"),
+    ("America/Dawson_Creek", "MST7"),
+    ("America/Denver", "MST7MDT,M3.2.0,M11.1.0"),
+    ("America/Detroit", "EST5EDT,M3.2.0,M11.1.0"),
+    ("America/Edmonton", "MST7MDT,M3.2.0,M11.1.0"),
+    ("America/Eirunepe", "<-05>5"),
+    ("America/El_Salvador", "CST6"),
+    ("America/Fort_Nelson", "MST7"),
+    ("America/Fortaleza", "<-03>3"),
+    ("America/Glace_Bay", "AST4ADT,M3.2.0,M11.1.0"),
+    ("America/Goose_Bay", "AST4ADT,M3.2.0,M11.1.0"),
+    ("America/Grand_Turk", "EST5EDT,M3.2.0,M11.1.0"),
+    ("America/Guatemala", "CST6"),
+    ("America/Guayaquil", "<-05>5"),
+    ("America/Guyana", "<-04>4"),
+    ("America/Halifax", "AST4ADT,M3.2.0,M11.1.0"),
+    ("America/Havana", "CST5CDT,M3.2.0/0,M11.1.0/1"),
+    ("America/Hermosillo", "MST7"),
+    ("America/Indiana/Indianapolis", "EST5EDT,M3.2.0,M11.1.0"),
+    ("America/Indiana/Knox", "CST6CDT,M3.2.0,M11.1.0"),
+    ("America/Indiana/Marengo", "EST5EDT,M3.2.0,M11.1.0"),
+    ("America/Indiana/Petersburg", "EST5EDT,M3.2.0,M11.1.0"),
+    ("America/Indiana/Tell_City", "CST6CDT,M3.2.0,M11.1.0"),
+    ("America/Indiana/Vevay", "EST5EDT,M3.2.0,M11.1.0"),
+    ("America/Indiana/Vincennes", "EST5EDT,M3.2.0,M11.1.0"),
+    ("America/Indiana/Winamac", "EST5EDT,M3.2.0,M11.1.0"),
+    ("America/Inuvik", "MST7MDT,M3.2.0,M11.1.0"),
+    ("America/Iqaluit", "EST5EDT,M3.2.0,M11.1.0"),
+    ("America/Jamaica", "EST5"),
+    ("America/Juneau", "AKST9AKDT,M3.2.0,M11.1.0"),
+    ("America/Kentucky/Louisville", "EST5EDT,M3.2.0,M11.1.0"),
+    ("America/Kentucky/Monticello", "EST5EDT,M3.2.0,M11.1.0"),
+    ("America/La_Paz", "<-04>4"),
+    ("America/Lima", "<-05>5"),
+    ("America/Los_Angeles", "PST8PDT,M3.2.0,M11.1.0"),
+    ("America/Maceio", "<-03>3"),
+    ("America/Managua", "CST6"),
+    ("America/Manaus", "<-04>4"),
+    ("America/Martinique", "AST4"),
+    ("America/Matamoros", "CST6CDT,M3.2.0,M11.1.0"),
+    ("America/Mazatlan", "MST7"),
+    ("America/Menominee", "CST6CDT,M3.2.0,M11.1.0"),
+    ("America/Merida", "CST6"),
+    ("America/Metlakatla", "AKST9AKDT,M3.2.0,M11.1.0"),
+    ("America/Mexico_City", "CST6"),
+    ("America/Miquelon", "<-03>3<-02>,M3.2.0,M11.1.0"),
+    ("America/Moncton", "AST4ADT,M3.2.0,M11.1.0"),
+    ("America/Monterrey", "CST6"),
+    ("America/Montevideo", "<-03>3"),
+    ("America/New_York", "EST5EDT,M3.2.0,M11.1.0"),
+    ("America/Nome", "AKST9AKDT,M3.2.0,M11.1.0"),
+    ("America/Noronha", "<-02>2"),
+    ("America/North_Dakota/Beulah", "CST6CDT,M3.2.0,M11.1.0"),
+    ("America/North_Dakota/Center", "CST6CDT,M3.2.0,M11.1.0"),
+    ("America/North_Dakota/New_Salem", "CST6CDT,M3.2.0,M11.1.0"),
+    ("America/Nuuk", "<-02>2<-01>,M3.5.0/-1,M10.5.0/0"),
+    ("America/Ojinaga", "CST6CDT,M3.2.0,M11.1.0"),
+    ("America/Panama", "EST5"),
+    ("America/Paramaribo", "<-03>3"),
+    ("America/Phoenix", "MST7"),
+    ("America/Port-au-Prince", "EST5EDT,M3.2.0,M11.1.0"),
+    ("America/Porto_Velho", "<-04>4"),
+    ("America/Puerto_Rico", "AST4"),
+    ("America/Punta_Arenas", "<-03>3"),
+    ("America/Rankin_Inlet", "CST6CDT,M3.2.0,M11.1.0"),
+    ("America/Recife", "<-03>3"),
+    ("America/Regina", "CST6"),
+    ("America/Resolute", "CST6CDT,M3.2.0,M11.1.0"),
+    ("America/Rio_Branco", "<-05>5"),
+    ("America/Santarem", "<-03>3"),
+    ("America/Santiago", "<-04>4<-03>,M9.1.6/24,M4.1.6/24"),
+    ("America/Santo_Domingo", "AST4"),
+    ("America/Sao_Paulo", "<-03>3"),
+    ("America/Scoresbysund", "<-02>2<-01>,M3.5.0/-1,M10.5.0/0"),
+    ("America/Sitka", "AKST9AKDT,M3.2.0,M11.1.0"),
+    ("America/St_Johns", "NST3:30NDT,M3.2.0,M11.1.0"),
+    ("America/Swift_Current", "CST6"),
+    ("America/Tegucigalpa", "CST6"),
+    ("America/Thule", "AST4ADT,M3.2.0,M11.1.0"),
+    ("America/Tijuana", "PST8PDT,M3.2.0,M11.1.0"),
+    ("America/Toronto", "EST5EDT,M3.2.0,M11.1.0"),
+    ("America/Vancouver", "PST8PDT,M3.2.0,M11.1.0"),
+    ("America/Whitehorse", "MST7"),
+    ("America/Winnipeg", "CST6CDT,M3.2.0,M11.1.0"),
+    ("America/Yakutat", "AKST9AKDT,M3.2.0,M11.1.0"),
+    ("Antarctica/Casey", "<+08>-8"),
+    ("Antarctica/Davis", "<+07>-7"),
+    ("Antarctica/Macquarie", "AEST-10AEDT,M10.1.0,M4.1.0/3"),
+    ("Antarctica/Mawson", "<+05>-5"),
+    ("Antarctica/Palmer", "<-03>3"),
+    ("Antarctica/Rothera", "<-03>3"),
+    ("Antarctica/Troll", "<+00>0<+02>-2,M3.5.0/1,M10.5.0/3"),
+    ("Antarctica/Vostok", "<+05>-5"),
+    ("Asia/Almaty", "<+05>-5"),
+    ("Asia/Amman", "<+03>-3"),
+    ("Asia/Anadyr", "<+12>-12"),
+    ("Asia/Aqtau", "<+05>-5"),
+    ("Asia/Aqtobe", "<+05>-5"),
+    ("Asia/Ashgabat", "<+05>-5"),
+    ("Asia/Atyrau", "<+05>-5"),
+    ("Asia/Baghdad", "<+03>-3"),
+    ("Asia/Baku", "<+04>-4"),
+    ("Asia/Bangkok", "<+07>-7"),
+    ("Asia/Barnaul", "<+07>-7"),
+    ("Asia/Beirut", "EET-2EEST,M3.5.0/0,M10.5.0/0"),
+    ("Asia/Bishkek", "<+06>-6"),
+    ("Asia/Chita", "<+09>-9"),
+    ("Asia/Colombo", "<+0530>-5:30"),
+    ("Asia/Damascus", "<+03>-3"),
+    ("Asia/Dhaka", "<+06>-6"),
+    ("Asia/Dili", "<+09>-9"),
+    ("Asia/Dubai", "<+04>-4"),
+    ("Asia/Dushanbe", "<+05>-5"),
+    ("Asia/Famagusta", "EET-2EEST,M3.5.0/3,M10.5.0/4"),
+    ("Asia/Gaza", "EET-2EEST,M3.4.4/50,M10.4.4/50"),
+    ("Asia/Hebron", "EET-2EEST,M3.4.4/50,M10.4.4/50"),
+    ("Asia/Ho_Chi_Minh", "<+07>-7"),
+    ("Asia/Hong_Kong", "HKT-8"),
+    ("Asia/Hovd", "<+07>-7"),
+    ("Asia/Irkutsk", "<+08>-8"),
+    ("Asia/Jakarta", "WIB-7"),
+    ("Asia/Jayapura", "WIT-9"),
+    ("Asia/Jerusalem", "IST-2IDT,M3.4.4/26,M10.5.0"),
+    ("Asia/Kabul", "<+0430>-4:30"),
+    ("Asia/Kamchatka", "<+12>-12"),
+    ("Asia/Karachi", "PKT-5"),
+    ("Asia/Kathmandu", "<+0545>-5:45"),
+    ("Asia/Khandyga", "<+09>-9"),
+    ("Asia/Kolkata", "IST-5:30"),
+    ("Asia/Krasnoyarsk", "<+07>-7"),
+    ("Asia/Kuching", "<+08>-8"),
+    ("Asia/Macau", "CST-8"),
+    ("Asia/Magadan", "<+11>-11"),
+    ("Asia/Makassar", "WITA-8"),
+    ("Asia/Manila", "PST-8"),
+    ("Asia/Nicosia", "EET-2EEST,M3.5.0/3,M10.5.0/4"),
+    ("Asia/Novokuznetsk", "<+07>-7"),
+    ("Asia/Novosibirsk", "<+07>-7"),
+    ("Asia/Omsk", "<+06>-6"),
+    ("Asia/Oral", "<+05>-5"),
+    ("Asia/Pontianak", "WIB-7"),
+    ("Asia/Pyongyang", "KST-9"),
+    ("Asia/Qatar", "<+03>-3"),
+    ("Asia/Qostanay", "<+05>-5"),
+    ("Asia/Qyzylorda", "<+05>-5"),
+    ("Asia/Riyadh", "<+03>-3"),
+    ("Asia/Sakhalin", "<+11>-11"),
+    ("Asia/Samarkand", "<+05>-5"),
+    ("Asia/Seoul", "KST-9"),
+    ("Asia/Shanghai", "CST-8"),
+    ("Asia/Singapore", "<+08>-8"),
+    ("Asia/Srednekolymsk", "<+11>-11"),
+    ("Asia/Taipei", "CST-8"),
+    ("Asia/Tashkent", "<+05>-5"),
+    ("Asia/Tbilisi", "<+04>-4"),
+    ("Asia/Tehran", "<+0330>-3:30"),
+    ("Asia/Thimphu", "<+06>-6"),
+    ("Asia/Tokyo", "JST-9"),
+    ("Asia/Tomsk", "<+07>-7"),
+    ("Asia/Ulaanbaatar", "<+08>-8"),
+    ("Asia/Urumqi", "<+06>-6"),
+    ("Asia/Ust-Nera", "<+10>-10"),
+    ("Asia/Vladivostok", "<+10>-10"),
+    ("Asia/Yakutsk", "<+09>-9"),
+    ("Asia/Yangon", "<+0630>-6:30"),
+    ("Asia/Yekaterinburg", "<+05>-5"),
+    ("Asia/Yerevan", "<+04>-4"),
+    ("Atlantic/Azores", "<-01>1<+00>,M3.5.0/0,M10.5.0/1"),
+    ("Atlantic/Bermuda", "AST4ADT,M3.2.0,M11.1.0"),
+    ("Atlantic/Canary", "WET0WEST,M3.5.0/1,M10.5.0"),
+    ("Atlantic/Cape_Verde", "<-01>1"),
+    ("Atlantic/Faroe", "WET0WEST,M3.5.0/1,M10.5.0"),
+    ("Atlantic/Madeira", "WET0WEST,M3.5.0/1,M10.5.0"),
+    ("Atlantic/South_Georgia", "<-02>2"),
+    ("Atlantic/Stanley", "<-03>3"),
+    ("Australia/Adelaide", "ACST-9:30ACDT,M10.1.0,M4.1.0/3"),
+    ("Australia/Brisbane", "AEST-10"),
+    ("Australia/Broken_Hill", "ACST-9:30ACDT,M10.1.0,M4.1.0/3"),
+    ("Australia/Darwin", "ACST-9:30"),
+    ("Australia/Eucla", "<+0845>-8:45"),
+    ("Australia/Hobart", "AEST-10AEDT,M10.1.0,M4.1.0/3"),
+    ("Australia/Lindeman", "AEST-10"),
+    (
+        "Australia/Lord_Howe",
+        "<+1030>-10:30<+11>-11,M10.1.0,M4.1.0",
+    ),
+    ("Australia/Melbourne", "AEST-10AEDT,M10.1.0,M4.1.0/3"),
+    ("Australia/Perth", "AWST-8"),
+    ("Australia/Sydney", "AEST-10AEDT,M10.1.0,M4.1.0/3"),
+    ("Etc/GMT", "GMT0"),
+    ("Etc/GMT+1", "<-01>1"),
+    ("Etc/GMT+10", "<-10>10"),
+    ("Etc/GMT+11", "<-11>11"),
+    ("Etc/GMT+12", "<-12>12"),
+    ("Etc/GMT+2", "<-02>2"),
+    ("Etc/GMT+3", "<-03>3"),
+    ("Etc/GMT+4", "<-04>4"),
+    ("Etc/GMT+5", "<-05>5"),
+    ("Etc/GMT+6", "<-06>6"),
+    ("Etc/GMT+7", "<-07>7"),
+    ("Etc/GMT+8", "<-08>8"),
+    ("Etc/GMT+9", "<-09>9"),
+    ("Etc/GMT-1", "<+01>-1"),
+    ("Etc/GMT-10", "<+10>-10"),
+    ("Etc/GMT-11", "<+11>-11"),
+    ("Etc/GMT-12", "<+12>-12"),
+    ("Etc/GMT-13", "<+13>-13"),
+    ("Etc/GMT-14", "<+14>-14"),
+    ("Etc/GMT-2", "<+02>-2"),
+    ("Etc/GMT-3", "<+03>-3"),
+    ("Etc/GMT-4", "<+04>-4"),
+    ("Etc/GMT-5", "<+05>-5"),
+    ("Etc/GMT-6", "<+06>-6"),
+    ("Etc/GMT-7", "<+07>-7"),
+    ("Etc/GMT-8", "<+08>-8"),
+    ("Etc/GMT-9", "<+09>-9"),
+    ("Etc/UTC", "UTC0"),
+    ("Europe/Andorra", "CET-1CEST,M3.5.0,M10.5.0/3"),
+    ("Europe/Astrakhan", "<+04>-4"),
+    ("Europe/Athens", "EET-2EEST,M3.5.0/3,M10.5.0/4"),
+    ("Europe/Belgrade", "CET-1CEST,M3.5.0,M10.5.0/3"),
+    ("Europe/Berlin", "CET-1CEST,M3.5.0,M10.5.0/3"),
+    ("Europe/Brussels", "CET-1CEST,M3.5.0,M10.5.0/3"),
+    ("Europe/Bucharest", "EET-2EEST,M3.5.0/3,M10.5.0/4"),
+    ("Europe/Budapest", "CET-1CEST,M3.5.0,M10.5.0/3"),
+    ("Europe/Chisinau", "EET-2EEST,M3.5.0,M10.5.0/3"),
+    ("Europe/Dublin", "IST-1GMT0,M10.5.0,M3.5.0/1"),
+    ("Europe/Gibraltar", "CET-1CEST,M3.5.0,M10.5.0/3"),
+    ("Europe/Helsinki", "EET-2EEST,M3.5.0/3,M10.5.0/4"),
+    ("Europe/Istanbul", "<+03>-3"),
+    ("Europe/Kaliningrad", "EET-2"),
+    ("Europe/Kirov", "MSK-3"),
+    ("Europe/Kyiv", "EET-2EEST,M3.5.0/3,M10.5.0/4"),
+    ("Europe/Lisbon", "WET0WEST,M3.5.0/1,M10.5.0"),
+    ("Europe/London", "GMT0BST,M3.5.0/1,M10.5.0"),
+    ("Europe/Madrid", "CET-1CEST,M3.5.0,M10.5.0/3"),
+    ("Europe/Malta", "CET-1CEST,M3.5.0,M10.5.0/3"),
+    ("Europe/Minsk", "<+03>-3"),
+    ("Europe/Moscow", "MSK-3"),
+    ("Europe/Paris", "CET-1CEST,M3.5.0,M10.5.0/3"),
+    ("Europe/Prague", "CET-1CEST,M3.5.0,M10.5.0/3"),
+    ("Europe/Riga", "EET-2EEST,M3.5.0/3,M10.5.0/4"),
+    ("Europe/Rome", "CET-1CEST,M3.5.0,M10.5.0/3"),
+    ("Europe/Samara", "<+04>-4"),
+    ("Europe/Saratov", "<+04>-4"),
+    ("Europe/Simferopol", "MSK-3"),
+    ("Europe/Sofia", "EET-2EEST,M3.5.0/3,M10.5.0/4"),
+    ("Europe/Tallinn", "EET-2EEST,M3.5.0/3,M10.5.0/4"),
+    ("Europe/Tirane", "CET-1CEST,M3.5.0,M10.5.0/3"),
+    ("Europe/Ulyanovsk", "<+04>-4"),
+    ("Europe/Vienna", "CET-1CEST,M3.5.0,M10.5.0/3"),
+    ("Europe/Vilnius", "EET-2EEST,M3.5.0/3,M10.5.0/4"),
+    ("Europe/Volgograd", "MSK-3"),
+    ("Europe/Warsaw", "CET-1CEST,M3.5.0,M10.5.0/3"),
+    ("Europe/Zurich", "CET-1CEST,M3.5.0,M10.5.0/3"),
+    ("Indian/Chagos", "<+06>-6"),
+    ("Indian/Maldives", "<+05>-5"),
+    ("Indian/Mauritius", "<+04>-4"),
+    ("Pacific/Apia", "<+13>-13"),
+    ("Pacific/Auckland", "NZST-12NZDT,M9.5.0,M4.1.0/3"),
+    ("Pacific/Bougainville", "<+11>-11"),
+    (
+        "Pacific/Chatham",
+        "<+1245>-12:45<+1345>,M9.5.0/2:45,M4.1.0/3:45",
+    ),
+    ("Pacific/Easter", "<-06>6<-05>,M9.1.6/22,M4.1.6/22"),
+    ("Pacific/Efate", "<+11>-11"),
+    ("Pacific/Fakaofo", "<+13>-13"),
+    ("Pacific/Fiji", "<+12>-12"),
+    ("Pacific/Galapagos", "<-06>6"),
+    ("Pacific/Gambier", "<-09>9"),
+    ("Pacific/Guadalcanal", "<+11>-11"),
+    ("Pacific/Guam", "ChST-10"),
+    ("Pacific/Honolulu", "HST10"),
+    ("Pacific/Kanton", "<+13>-13"),
+    ("Pacific/Kiritimati", "<+14>-14"),
+    ("Pacific/Kosrae", "<+11>-11"),
+    ("Pacific/Kwajalein", "<+12>-12"),
+    ("Pacific/Marquesas", "<-0930>9:30"),
+    ("Pacific/Nauru", "<+12>-12"),
+    ("Pacific/Niue", "<-11>11"),
+    ("Pacific/Norfolk", "<+11>-11<+12>,M10.1.0,M4.1.0/3"),
+    ("Pacific/Noumea", "<+11>-11"),
+    ("Pacific/Pago_Pago", "SST11"),
+    ("Pacific/Palau", "<+09>-9"),
+    ("Pacific/Pitcairn", "<-08>8"),
+    ("Pacific/Port_Moresby", "<+10>-10"),
+    ("Pacific/Rarotonga", "<-10>10"),
+    ("Pacific/Tahiti", "<-10>10"),
+    ("Pacific/Tarawa", "<+12>-12"),
+    ("Pacific/Tongatapu", "<+13>-13"),
+];
@@ -0,0 +1,225 @@
+//! Lookups in embedded copies of the IANA time zone database and of the CLDR mapping of Windows
+//! time zones.
+
+use crate::tzdata::{LINKS, NAMES};
+use crate::windows_zones::WINDOWS_ZONES;
+
+/// Check if `name` is a time zone or link of the IANA time zone database.
+///
+/// The check uses an embedded list of the names, so no installed tzdata is needed. Names are
+/// case-sensitive, and the `posix/` and `right/` variants are not included.
+///
+/// ```rust
+/// assert!(iana_time_zone::is_valid_timezone("Europe/Berlin"));
+/// assert!(iana_time_zone::is_valid_timezone("US/Eastern"));
+/// assert!(!iana_time_zone::is_valid_timezone("europe/berlin"));
+/// assert!(!iana_time_zone::is_valid_timezone("CET-1CEST,M3.5.0,M10.5.0/3"));
+/// ```
+pub fn is_valid_timezone(name: &str) -> bool {
+    NAMES.binary_search(&name).is_ok()
+}
+
+/// Map a backward compatible alias to its primary IANA time zone name.
+///
+/// Many systems are still configured with names that were renamed or merged into other zones,
+/// e.g. `"US/Eastern"`, `"Asia/Calcutta"` or `"Europe/Kiev"`. This function maps the links of
+/// the tzdata file `backward` to their targets. Other names are returned unchanged.
+///
+/// The table is embedded in the crate, so no installed tzdata is needed.
+///
+/// ```rust
+/// assert_eq!(iana_time_zone::canonicalize("Asia/Calcutta"), "Asia/Kolkata");
+/// assert_eq!(iana_time_zone::canonicalize("Europe/Berlin"), "Europe/Berlin");
+///
+/// let tz_str = iana_time_zone::get_timezone()?;
+/// println!("The current time zone is: {}", iana_time_zone::canonicalize(&tz_str));
+/// # Ok::<(), iana_time_zone::GetTimezoneError>(())
+/// ```
+pub fn canonicalize(name: &str) -> &str {
+    match LINKS.binary_search_by_key(&name, |&(alias, _)| alias) {
+        Ok(idx) => LINKS[idx].1,
+        Err(_) => name,
+    }
+}
+
+/// Return the backward compatible aliases of a primary IANA time zone name.
+///
+/// This is the reverse of [`canonicalize()`]. The aliases are sorted, and the result is empty
+/// if `name` has no aliases, or if it is an alias itself.
+///
+/// ```rust
+/// let aliases = iana_time_zone::aliases("Asia/Kolkata");
+/// assert_eq!(aliases, ["Asia/Calcutta"]);
+/// ```
+pub fn aliases(name: &str) -> Vec<&'static str> {
+    LINKS
+        .iter()
+        .filter(|&&(_, target)| target == name)
+        .map(|&(alias, _)| alias)
+        .collect()
+}
+
+/// Map a Windows time zone ID to an IANA time zone name.
+///
+/// The mapping uses an embedded copy of the CLDR file `windowsZones.xml`, so it is available on
+/// every platform. `territory` is a two-letter ISO 3166 region code, e.g. `"CH"`, that selects
+/// the zone of a region if the Windows time zone covers multiple IANA time zones. Without a
+/// territory, or if the territory is unknown, the main zone of the Windows time zone is used.
+///
+/// The names are the ones used by CLDR, which may be backward compatible aliases like
+/// `"Asia/Calcutta"`. Use [`canonicalize()`] to get the primary name.
+///
+/// ```rust
+/// use iana_time_zone::windows_to_iana;
+///
+/// assert_eq!(windows_to_iana("W. Europe Standard Time", None), Some("Europe/Berlin"));
+/// assert_eq!(windows_to_iana("W. Europe Standard Time", Some("CH")), Some("Europe/Zurich"));
+/// assert_eq!(windows_to_iana("Not a time zone", None), None);
+/// ```
+pub fn windows_to_iana(id: &str, territory: Option<&str>) -> Option<&'static str> {
+    let find = |territory: &str| {
+        WINDOWS_ZONES
+            .binary_search_by(|&(other, t, _)| (other, t).cmp(&(id, territory)))
+            .ok()
+    };
+    let idx = territory.and_then(find).or_else(|| find("001"))?;
+    WINDOWS_ZONES[idx].2.first().copied()
+}
+
+/// Map an IANA time zone name to a Windows time zone ID.
+///
+/// This is the reverse of [`windows_to_iana()`]. Backward compatible aliases are accepted, too.
+///
+/// ```rust
+/// use iana_time_zone::iana_to_windows;
+///
+/// assert_eq!(iana_to_windows("Europe/Zurich"), Some("W. Europe Standard Time"));
+/// assert_eq!(iana_to_windows("Asia/Kolkata"), Some("India Standard Time"));
+/// assert_eq!(iana_to_windows("Not/A_Zone"), None);
+/// ```
+pub fn iana_to_windows(name: &str) -> Option<&'static str> {
+    let find = |matches: &dyn Fn(&str) -> bool| {
+        WINDOWS_ZONES
+            .iter()
+            .find(|(_, _, ids)| ids.iter().any(|&id| matches(id)))
+            .map(|&(other, _, _)| other)
+    };
+    // Merged zones may map to different Windows time zones than their targets, e.g.
+    // "Europe/Amsterdam" and "Europe/Brussels", so the exact name is preferred.
+    find(&|id| id == name).or_else(|| {
+        let name = canonicalize(name);
+        find(&|id| canonicalize(id) == name)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        aliases, canonicalize, iana_to_windows, is_valid_timezone, windows_to_iana, LINKS, NAMES,
+        WINDOWS_ZONES,
+    };
+
+    #[test]
+    fn test_names_sorted() {
+        assert!(NAMES.windows(2).all(|w| w[0] < w[1]));
+        for &(alias, target) in LINKS {
+            assert!(is_valid_timezone(alias));
+            assert!(is_valid_timezone(target));
+        }
+    }
+
+    #[test]
+    fn test_is_valid_timezone() {
+        assert!(is_valid_timezone("America/Argentina/Buenos_Aires"));
+        assert!(is_valid_timezone("Etc/GMT-14"));
+        assert!(is_valid_timezone("UTC"));
+        assert!(!is_valid_timezone(""));
+        assert!(!is_valid_timezone("Europe"));
+        assert!(!is_valid_timezone("posix/Europe/Berlin"));
+        assert!(!is_valid_timezone("Factory"));
+        assert!(!is_valid_timezone("Europe/Berlin\n"));
+    }
+
+    #[test]
+    fn test_links_sorted() {
+        assert!(LINKS.windows(2).all(|w| w[0].0 < w[1].0));
+        // tzdata does not link to links.
+        for &(_, target) in LINKS {
+            assert_eq!(canonicalize(target), target);
+        }
+    }
+
+    #[test]
+    fn test_canonicalize() {
+        assert_eq!(canonicalize("US/Eastern"), "America/New_York");
+        assert_eq!(canonicalize("Asia/Calcutta"), "Asia/Kolkata");
+        assert_eq!(canonicalize("Europe/Kiev"), "Europe/Kyiv");
+        assert_eq!(canonicalize("America/New_York"), "America/New_York");
+        assert_eq!(canonicalize("Not/A_Zone"), "Not/A_Zone");
+    }
+
+    #[test]
+    fn test_aliases() {
+        assert_eq!(
+            aliases("Europe/Kyiv"),
+            ["Europe/Kiev", "Europe/Uzhgorod", "Europe/Zaporozhye"]
+        );
+        assert!(aliases("America/New_York").contains(&"US/Eastern"));
+        assert!(aliases("US/Eastern").is_empty());
+        assert!(aliases("Not/A_Zone").is_empty());
+    }
+
+    #[test]
+    fn test_windows_zones() {
+        assert!(WINDOWS_ZONES
+            .windows(2)
+            .all(|w| (w[0].0, w[0].1) < (w[1].0, w[1].1)));
+        for &(other, _, ids) in WINDOWS_ZONES {
+            assert!(!ids.is_empty());
+            for &id in ids {
+                assert!(is_valid_timezone(id), "{}", id);
+                assert_eq!(iana_to_windows(id), Some(other));
+            }
+            assert!(windows_to_iana(other, None).is_some());
+        }
+    }
+
+    #[test]
+    fn test_windows_to_iana() {
+        assert_eq!(
+            windows_to_iana("Eastern Standard Time", None),
+            Some("America/New_York")
+        );
+        assert_eq!(
+            windows_to_iana("Eastern Standard Time", Some("CA")),
+            Some("America/Toronto")
+        );
+        // An unknown territory falls back to the main zone.
+        assert_eq!(
+            windows_to_iana("Eastern Standard Time", Some("DE")),
+            Some("America/New_York")
+        );
+        assert_eq!(windows_to_iana("UTC", None), Some("Etc/UTC"));
+        assert_eq!(windows_to_iana("eastern standard time", None), None);
+    }
+
+    #[test]
+    fn test_iana_to_windows() {
+        assert_eq!(
+            iana_to_windows("Europe/Berlin"),
+            Some("W. Europe Standard Time")
+        );
+        assert_eq!(
+            iana_to_windows("Europe/Amsterdam"),
+            Some("W. Europe Standard Time")
+        );
+        assert_eq!(
+            iana_to_windows("Europe/Brussels"),
+            Some("Romance Standard Time")
+        );
+        assert_eq!(iana_to_windows("US/Eastern"), Some("Eastern Standard Time"));
+        assert_eq!(iana_to_windows("Europe/Kyiv"), Some("FLE Standard Time"));
+        assert_eq!(iana_to_windows("Etc/UTC"), Some("UTC"));
+        assert_eq!(iana_to_windows("UTC"), Some("UTC"));
+    }
+}
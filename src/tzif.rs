@@ -0,0 +1,409 @@
+//! The TZif format of the files in the zoneinfo database.
+//!
+//! See [RFC 8536](https://datatracker.ietf.org/doc/html/rfc8536). Version 1 files only contain
+//! 32-bit transition times. Version 2 files add a second data block with 64-bit transition
+//! times, and a footer with the POSIX TZ rule string for the times after the last transition.
+//! Versions 3 and 4 only relax the rules for the footer and for the leap second records, which
+//! this reader does not check anyway.
+
+use crate::posix_tz::{LocalType, PosixTz};
+
+/// The parsed contents of a TZif file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct Tzif {
+    /// The transition times in ascending order, and the index of the local time type that
+    /// starts at each of them.
+    pub(crate) transitions: Vec<(i64, usize)>,
+    /// The local time types, and whether they are daylight saving time.
+    pub(crate) local_types: Vec<(LocalType, bool)>,
+    /// The leap second records as the time of the leap second, and the total correction after it.
+    pub(crate) leap_seconds: Vec<(i64, i32)>,
+    /// The rule of the times after the last transition.
+    pub(crate) footer: Option<PosixTz>,
+}
+
+/// The counts of the header of a data block.
+#[derive(Debug, Clone, Copy)]
+struct Header {
+    version: u8,
+    isutcnt: usize,
+    isstdcnt: usize,
+    leapcnt: usize,
+    timecnt: usize,
+    typecnt: usize,
+    charcnt: usize,
+}
+
+impl Tzif {
+    /// Parse a TZif file of version 1 to 4.
+    pub(crate) fn parse(data: &[u8]) -> Option<Self> {
+        let mut reader = Reader(data);
+        let header = reader.header()?;
+        if header.version == 0 {
+            return reader.block(&header, 4);
+        }
+
+        // Skip the data block with 32-bit times.
+        reader.take(usize::try_from(header.block_len(4)).ok()?)?;
+        let header = reader.header()?;
+        let mut tzif = reader.block(&header, 8)?;
+
+        let footer = reader.0.strip_prefix(b"\n")?;
+        let len = footer.iter().position(|&b| b == b'\n')?;
+        let footer = std::str::from_utf8(&footer[..len]).ok()?;
+        if !footer.is_empty() {
+            tzif.footer = Some(PosixTz::parse(footer).ok()?);
+        }
+        Some(tzif)
+    }
+
+    /// A time zone without transitions, whose rules are only described by `rule`.
+    pub(crate) fn from_posix_tz(rule: PosixTz) -> Self {
+        let (std, _) = rule.local_type_at(i64::MIN);
+        Self {
+            transitions: Vec::new(),
+            local_types: vec![(std.clone(), false)],
+            leap_seconds: Vec::new(),
+            footer: Some(rule),
+        }
+    }
+
+    /// The local time type at `unix_time`, and whether it is daylight saving time.
+    pub(crate) fn local_type_at(&self, unix_time: i64) -> (&LocalType, bool) {
+        // The times in the file count leap seconds, if it has any.
+        let time = unix_time.saturating_add(i64::from(self.leap_correction(unix_time)));
+        match self.transitions.last() {
+            Some(&(last, _)) if time >= last => {}
+            Some(_) => {
+                let idx = self.transitions.partition_point(|&(t, _)| t <= time);
+                let local_type = match idx {
+                    // Before the first transition, the first local time type applies.
+                    0 => &self.local_types[0],
+                    _ => &self.local_types[self.transitions[idx - 1].1],
+                };
+                return (&local_type.0, local_type.1);
+            }
+            None => {}
+        }
+        if let Some(footer) = &self.footer {
+            return footer.local_type_at(unix_time);
+        }
+        let idx = self.transitions.last().map_or(0, |&(_, idx)| idx);
+        let local_type = &self.local_types[idx];
+        (&local_type.0, local_type.1)
+    }
+
+    /// The times at or after `from` at which the local time type changes, as Unix times in
+    /// ascending order.
+    ///
+    /// After the last transition in the file, the transitions are generated from the footer.
+    pub(crate) fn transitions_from(&self, from: i64) -> impl Iterator<Item = i64> + '_ {
+        let idx = self
+            .transitions
+            .partition_point(|&(time, _)| self.unix_time(time) < from);
+        let explicit = self.transitions[idx..]
+            .iter()
+            .map(move |&(time, _)| self.unix_time(time))
+            .filter(move |&time| {
+                self.local_type_at(time.saturating_sub(1)) != self.local_type_at(time)
+            });
+        let after_last = match self.transitions.last() {
+            Some(&(last, _)) => from.max(self.unix_time(last).saturating_add(1)),
+            None => from,
+        };
+        let generated = self
+            .footer
+            .iter()
+            .flat_map(move |footer| footer.transitions_from(after_last));
+        explicit.chain(generated)
+    }
+
+    /// The Unix time of the `time` in the file.
+    fn unix_time(&self, time: i64) -> i64 {
+        let correction = self
+            .leap_seconds
+            .iter()
+            .take_while(|&&(leap, _)| leap < time)
+            .last()
+            .map_or(0, |&(_, correction)| correction);
+        time - i64::from(correction)
+    }
+
+    /// The difference between the times in the file and `unix_time`.
+    fn leap_correction(&self, unix_time: i64) -> i32 {
+        self.leap_seconds
+            .iter()
+            .take_while(|&&(time, correction)| time - i64::from(correction) < unix_time)
+            .last()
+            .map_or(0, |&(_, correction)| correction)
+    }
+}
+
+impl Header {
+    /// The length of the data block, if its times have `time_size` bytes.
+    ///
+    /// The counts are 32-bit numbers, so the length cannot overflow a `u64`.
+    fn block_len(&self, time_size: u64) -> u64 {
+        let count = |count: usize| count as u64;
+        count(self.timecnt) * (time_size + 1)
+            + count(self.typecnt) * 6
+            + count(self.charcnt)
+            + count(self.leapcnt) * (time_size + 4)
+            + count(self.isstdcnt)
+            + count(self.isutcnt)
+    }
+}
+
+/// A reader of big-endian values, which returns `None` at the end of the data.
+struct Reader<'a>(&'a [u8]);
+
+impl<'a> Reader<'a> {
+    fn take(&mut self, len: usize) -> Option<&'a [u8]> {
+        if self.0.len() < len {
+            return None;
+        }
+        let (taken, rest) = self.0.split_at(len);
+        self.0 = rest;
+        Some(taken)
+    }
+
+    fn array<const N: usize>(&mut self) -> Option<[u8; N]> {
+        let mut array = [0; N];
+        array.copy_from_slice(self.take(N)?);
+        Some(array)
+    }
+
+    fn count(&mut self) -> Option<usize> {
+        usize::try_from(u32::from_be_bytes(self.array()?)).ok()
+    }
+
+    fn time(&mut self, time_size: usize) -> Option<i64> {
+        match time_size {
+            4 => Some(i64::from(i32::from_be_bytes(self.array()?))),
+            _ => Some(i64::from_be_bytes(self.array()?)),
+        }
+    }
+
+    fn header(&mut self) -> Option<Header> {
+        if self.take(4)? != b"TZif" {
+            return None;
+        }
+        let version = self.array::<1>()?[0];
+        if version != 0 && version < b'2' {
+            return None;
+        }
+        self.take(15)?;
+        let header = Header {
+            version,
+            isutcnt: self.count()?,
+            isstdcnt: self.count()?,
+            leapcnt: self.count()?,
+            timecnt: self.count()?,
+            typecnt: self.count()?,
+            charcnt: self.count()?,
+        };
+        let valid = header.typecnt != 0
+            && header.charcnt != 0
+            && (header.isutcnt == 0 || header.isutcnt == header.typecnt)
+            && (header.isstdcnt == 0 || header.isstdcnt == header.typecnt);
+        // The counts are checked against the length of the data, before anything is allocated.
+        if !valid || (self.0.len() as u64) < header.block_len(4) {
+            return None;
+        }
+        Some(header)
+    }
+
+    fn block(&mut self, header: &Header, time_size: usize) -> Option<Tzif> {
+        let mut times = Vec::with_capacity(header.timecnt);
+        for _ in 0..header.timecnt {
+            times.push(self.time(time_size)?);
+        }
+        if !times.windows(2).all(|w| w[0] < w[1]) {
+            return None;
+        }
+        let indices = self.take(header.timecnt)?;
+        if indices
+            .iter()
+            .any(|&idx| usize::from(idx) >= header.typecnt)
+        {
+            return None;
+        }
+        let transitions = times
+            .into_iter()
+            .zip(indices.iter().map(|&idx| usize::from(idx)))
+            .collect();
+
+        let mut infos = Vec::with_capacity(header.typecnt);
+        for _ in 0..header.typecnt {
+            let utc_offset = i32::from_be_bytes(self.array()?);
+            let [is_dst, abbreviation_idx] = self.array()?;
+            if utc_offset == i32::MIN || is_dst > 1 {
+                return None;
+            }
+            infos.push((utc_offset, is_dst == 1, usize::from(abbreviation_idx)));
+        }
+        let chars = self.take(header.charcnt)?;
+        let local_types = infos
+            .into_iter()
+            .map(|(utc_offset, is_dst, idx)| {
+                let abbreviation = chars.get(idx..)?;
+                let len = abbreviation.iter().position(|&b| b == 0)?;
+                let abbreviation = String::from_utf8(abbreviation[..len].to_vec()).ok()?;
+                let local_type = LocalType {
+                    abbreviation,
+                    utc_offset,
+                };
+                Some((local_type, is_dst))
+            })
+            .collect::<Option<_>>()?;
+
+        let mut leap_seconds = Vec::with_capacity(header.leapcnt);
+        for _ in 0..header.leapcnt {
+            let time = self.time(time_size)?;
+            let correction = i32::from_be_bytes(self.array()?);
+            leap_seconds.push((time, correction));
+        }
+        // The standard/wall and UT/local indicators only matter for POSIX TZ rules without
+        // transitions, which are not supported.
+        self.take(header.isstdcnt + header.isutcnt)?;
+
+        Some(Tzif {
+            transitions,
+            local_types,
+            leap_seconds,
+            footer: None,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Tzif;
+
+    const BERLIN: &[u8] = include_bytes!("../tests/fixtures/tzif/Europe/Berlin");
+    const JERUSALEM: &[u8] = include_bytes!("../tests/fixtures/tzif/Asia/Jerusalem");
+    const SYDNEY: &[u8] = include_bytes!("../tests/fixtures/tzif/Australia/Sydney");
+    const RIGHT_BERLIN: &[u8] = include_bytes!("../tests/fixtures/tzif/right/Europe/Berlin");
+
+    fn at(tzif: &Tzif, unix_time: i64) -> (&str, i32, bool) {
+        let (local_type, is_dst) = tzif.local_type_at(unix_time);
+        (&local_type.abbreviation, local_type.utc_offset, is_dst)
+    }
+
+    #[test]
+    fn test_parse() {
+        let berlin = Tzif::parse(BERLIN).unwrap();
+        assert_eq!(
+            berlin.footer.as_ref().unwrap().to_string(),
+            "CET-1CEST,M3.5.0,M10.5.0/3"
+        );
+        assert!(berlin.leap_seconds.is_empty());
+        assert_eq!(at(&berlin, -3_000_000_000), ("LMT", 3208, false));
+        assert_eq!(at(&berlin, 0), ("CET", 3600, false));
+        assert_eq!(at(&berlin, 1_711_846_799), ("CET", 3600, false));
+        assert_eq!(at(&berlin, 1_711_846_800), ("CEST", 7200, true));
+        // 1945, Berlin had double summer time.
+        assert_eq!(at(&berlin, -773_280_000), ("CEMT", 10800, true));
+
+        // Version 3 allows transition times of more than 24 hours in the footer.
+        let jerusalem = Tzif::parse(JERUSALEM).unwrap();
+        assert_eq!(
+            jerusalem.footer.as_ref().unwrap().to_string(),
+            "IST-2IDT,M3.4.4/26,M10.5.0"
+        );
+        assert_eq!(at(&jerusalem, 4_102_444_800), ("IST", 7200, false)); // 2100-01-01
+
+        let sydney = Tzif::parse(SYDNEY).unwrap();
+        assert_eq!(at(&sydney, 1_704_067_200), ("AEDT", 39600, true));
+        assert_eq!(at(&sydney, 1_719_792_000), ("AEST", 36000, false));
+    }
+
+    /// The offset of the second header of a TZif file of version 2 or later.
+    fn second_header(data: &[u8]) -> usize {
+        let count = |idx: usize| {
+            let bytes = data[20 + 4 * idx..24 + 4 * idx].try_into().unwrap();
+            u32::from_be_bytes(bytes) as usize
+        };
+        let (isutcnt, isstdcnt, leapcnt, timecnt, typecnt, charcnt) =
+            (count(0), count(1), count(2), count(3), count(4), count(5));
+        44 + timecnt * 5 + typecnt * 6 + charcnt + leapcnt * 8 + isstdcnt + isutcnt
+    }
+
+    #[test]
+    fn test_versions() {
+        // Version 1 files only have the first data block.
+        let v2 = Tzif::parse(BERLIN).unwrap();
+        let mut v1 = BERLIN[..second_header(BERLIN)].to_vec();
+        v1[4] = 0;
+        let v1 = Tzif::parse(&v1).unwrap();
+        assert_eq!(v1.footer, None);
+        assert_eq!(at(&v1, 1_711_846_800), at(&v2, 1_711_846_800));
+        assert_eq!(at(&v1, 1_704_067_200), at(&v2, 1_704_067_200));
+
+        // Version 4 files may have a truncated leap second table, which is not checked.
+        let mut v4 = RIGHT_BERLIN.to_vec();
+        v4[4] = b'4';
+        v4[second_header(RIGHT_BERLIN) + 4] = b'4';
+        assert_eq!(Tzif::parse(&v4), Tzif::parse(RIGHT_BERLIN));
+
+        assert_eq!(Tzif::parse(b""), None);
+        assert_eq!(Tzif::parse(b"TZif1"), None);
+        assert_eq!(Tzif::parse(&BERLIN[..BERLIN.len() - 1]), None);
+        assert_eq!(Tzif::parse(&BERLIN[..1000]), None);
+    }
+
+    #[test]
+    fn test_leap_seconds() {
+        let berlin = Tzif::parse(BERLIN).unwrap();
+        let right = Tzif::parse(RIGHT_BERLIN).unwrap();
+        assert_eq!(right.leap_seconds.len(), 27);
+        assert_eq!(right.leap_seconds.last().unwrap().1, 27);
+        // The transitions are at the same Unix times.
+        for &unix_time in &[78_796_799, 78_796_800, 1_711_846_799, 1_711_846_800] {
+            assert_eq!(at(&right, unix_time), at(&berlin, unix_time));
+        }
+    }
+
+    #[test]
+    fn test_footer() {
+        // The footer describes the same transitions as the file contains for 2024 to 2037.
+        for data in [BERLIN, JERUSALEM, SYDNEY] {
+            let tzif = Tzif::parse(data).unwrap();
+            let rule = Tzif::from_posix_tz(tzif.footer.clone().unwrap());
+            let since_2024 = tzif
+                .transitions
+                .iter()
+                .filter(|&&(t, _)| t >= 1_704_067_200);
+            for &(time, _) in since_2024 {
+                assert_eq!(at(&tzif, time), at(&rule, time));
+                assert_eq!(at(&tzif, time - 1), at(&rule, time - 1));
+            }
+        }
+    }
+
+    #[test]
+    fn test_transitions_from() {
+        let berlin = Tzif::parse(BERLIN).unwrap();
+        let mut transitions = berlin.transitions_from(1_704_067_200);
+        assert_eq!(transitions.next(), Some(1_711_846_800));
+        assert_eq!(transitions.next(), Some(1_729_990_800));
+
+        // After 2037, the transitions are generated from the footer.
+        let rule = Tzif::from_posix_tz(berlin.footer.clone().unwrap());
+        let from_file = berlin.transitions_from(1_704_067_200).take(100);
+        assert!(from_file.eq(rule.transitions_from(1_704_067_200).take(100)));
+
+        // The same transitions until 2025, but the file counts leap seconds, and has no footer.
+        let right = Tzif::parse(RIGHT_BERLIN).unwrap();
+        let from_file = berlin
+            .transitions_from(0)
+            .take_while(|&t| t < 1_735_689_600);
+        assert!(from_file.eq(right.transitions_from(0).take_while(|&t| t < 1_735_689_600)));
+
+        // Transitions that do not change the local time type are skipped.
+        assert!(berlin
+            .transitions_from(i64::MIN)
+            .take_while(|&t| t < 1_704_067_200)
+            .all(|t| at(&berlin, t - 1) != at(&berlin, t)));
+    }
+}
@@ -0,0 +1,936 @@
+//! The sources shared by the Unix-like backends that read the time zone from the file system.
+//!
+//! All sources take the `root` of the file system to inspect, which is `/` for the running
+//! system, so they can also be used for [`get_timezone_in_root()`](crate::get_timezone_in_root).
+
+use std::env;
+use std::io;
+use std::path::{Component, Path, PathBuf};
+use std::sync::RwLock;
+
+use crate::detector::first_of;
+use crate::filesystem::{FileSystem, StdFs};
+use crate::lazy::Lazy;
+use crate::{BuiltinSource, Context, Detection, GetTimezoneError, Source};
+
+/// The sources of Linux and GNU/Hurd, in order.
+pub(crate) const LINUX: &[BuiltinSource] = &[
+    BuiltinSource::TZ_ENV,
+    BuiltinSource::ETC_LOCALTIME,
+    BuiltinSource::ETC_TIMEZONE,
+    BuiltinSource::OPENWRT,
+    BuiltinSource::ETC_LOCALTIME_COPY,
+];
+
+/// The sources of FreeBSD and DragonFly BSD, in order.
+pub(crate) const FREEBSD: &[BuiltinSource] = &[
+    BuiltinSource::TZ_ENV,
+    BuiltinSource::VAR_DB_ZONEINFO,
+    BuiltinSource::ETC_LOCALTIME_COPY,
+];
+
+// see https://www.cyberciti.biz/faq/openbsd-time-zone-howto/
+//
+// This is a backport of the Linux implementation.
+// NetBSDs is less than thorough how the softlink should be set up.
+/// The sources of NetBSD and OpenBSD, in order.
+pub(crate) const NETBSD: &[BuiltinSource] = &[
+    BuiltinSource::TZ_ENV,
+    BuiltinSource::ETC_LOCALTIME,
+    BuiltinSource::ETC_LOCALTIME_COPY,
+];
+
+/// The sources of illumos and Solaris, in order.
+pub(crate) const ILLUMOS: &[BuiltinSource] =
+    &[BuiltinSource::TZ_ENV, BuiltinSource::ETC_DEFAULT_INIT];
+
+/// The sources of AIX.
+pub(crate) const AIX: &[BuiltinSource] = &[BuiltinSource::TZ_ENV];
+
+/// The sources of [`detect_in_root()`](crate::detect_in_root), in order.
+///
+/// The `TZ` environment variable only applies to the running system, so it is not consulted.
+pub(crate) const IN_ROOT: &[BuiltinSource] = &[
+    BuiltinSource::ETC_LOCALTIME,
+    BuiltinSource::ETC_TIMEZONE,
+    BuiltinSource::OPENWRT,
+    BuiltinSource::VAR_DB_ZONEINFO,
+    BuiltinSource::ETC_DEFAULT_INIT,
+    BuiltinSource::ETC_LOCALTIME_COPY,
+];
+
+/// Detect the time zone configured in the file system at `root`.
+pub(crate) fn detect_in_root(root: &Path) -> Result<Detection, GetTimezoneError> {
+    first_of(&StdFs, root, IN_ROOT)
+}
+
+/// Find the TZif file of the time zone `name` in the zoneinfo database inside `root`.
+pub(crate) fn zoneinfo_file(fs: &dyn FileSystem, root: &Path, name: &str) -> Option<PathBuf> {
+    let relative = Path::new(name);
+    if !relative
+        .components()
+        .all(|c| matches!(c, Component::Normal(_)))
+    {
+        return None;
+    }
+    zoneinfo_prefixes(root).into_iter().find_map(|prefix| {
+        let path = in_root(root, prefix).join(relative);
+        fs.read(&path)
+            .ok()
+            .filter(|contents| contents.starts_with(b"TZif"))
+            .map(|_| path)
+    })
+}
+
+/// Find the TZif file of a time zone that was detected in the file system at `root`.
+///
+/// If the time zone was read from a symlink or a copy, i.e. `/etc/localtime`, then this is that
+/// file, so its rules are the ones the C library uses. Otherwise the file is looked up in the
+/// zoneinfo database.
+pub(crate) fn tzif_path(
+    fs: &dyn FileSystem,
+    root: &Path,
+    detection: &Detection,
+) -> Option<PathBuf> {
+    match (&detection.path, detection.source) {
+        (Some(path), Source::Symlink | Source::FileCopy) => Some(path.clone()),
+        _ if detection.leap_seconds => {
+            zoneinfo_file(fs, root, &format!("right/{}", detection.name))
+        }
+        _ => zoneinfo_file(fs, root, &detection.name),
+    }
+}
+
+/// The path of the absolute `path` inside the file system at `root`.
+pub(crate) fn in_root(root: &Path, path: impl AsRef<Path>) -> PathBuf {
+    let path = path.as_ref();
+    root.join(path.strip_prefix("/").unwrap_or(path))
+}
+
+/// Locations of the zoneinfo database that may prefix the target of `/etc/localtime`.
+const PREFIXES: &[&str] = &[
+    "/usr/share/zoneinfo/",     // most systems
+    "/usr/lib/zoneinfo/",       // older Linux distributions
+    "/usr/share/lib/zoneinfo/", // illumos and Solaris
+    "/etc/zoneinfo/",           // NixOS
+];
+
+/// Locations of the zoneinfo database that were added with [`register_zoneinfo_prefix()`].
+static REGISTERED_PREFIXES: Lazy<RwLock<Vec<String>>> = Lazy::new(|| RwLock::new(Vec::new()));
+
+/// Register an additional location of the zoneinfo database.
+///
+/// If a symlink like `/etc/localtime` points into `prefix`, then the rest of its target is used as
+/// the time zone name. `prefix` must be an absolute path. Registered prefixes are tried before the
+/// prefixes in `$TZDIR`, and before the built-in ones, i.e. `/usr/share/zoneinfo/`,
+/// `/usr/lib/zoneinfo/`, `/usr/share/lib/zoneinfo/` and `/etc/zoneinfo/`.
+///
+/// ```rust
+/// iana_time_zone::register_zoneinfo_prefix("/opt/vendor/share/zoneinfo");
+/// ```
+pub fn register_zoneinfo_prefix(prefix: impl Into<String>) {
+    let mut prefix = prefix.into();
+    if !prefix.ends_with('/') {
+        prefix.push('/');
+    }
+    let mut prefixes = match REGISTERED_PREFIXES.get().write() {
+        Ok(prefixes) => prefixes,
+        Err(err) => err.into_inner(),
+    };
+    if !prefixes.contains(&prefix) {
+        prefixes.push(prefix);
+    }
+}
+
+/// The number of prefixes that were registered with [`register_zoneinfo_prefix()`]. It only
+/// grows, so it changes whenever a prefix is added.
+pub(crate) fn registered_prefixes() -> usize {
+    match REGISTERED_PREFIXES.get().read() {
+        Ok(prefixes) => prefixes.len(),
+        Err(err) => err.into_inner().len(),
+    }
+}
+
+/// All locations of the zoneinfo database for the file system at `root`, in order of preference.
+///
+/// `$TZDIR` only applies to the running system.
+pub(crate) fn zoneinfo_prefixes(root: &Path) -> Vec<String> {
+    let mut prefixes = match REGISTERED_PREFIXES.get().read() {
+        Ok(prefixes) => prefixes.clone(),
+        Err(err) => err.into_inner().clone(),
+    };
+    if root == Path::new("/") {
+        if let Ok(mut tzdir) = env::var("TZDIR") {
+            if tzdir.starts_with('/') {
+                if !tzdir.ends_with('/') {
+                    tzdir.push('/');
+                }
+                prefixes.push(tzdir);
+            }
+        }
+    }
+    prefixes.extend(PREFIXES.iter().map(|&prefix| prefix.to_owned()));
+    prefixes
+}
+
+/// Strip one of the `prefixes` from a path into the zoneinfo database.
+///
+/// Nix and Guix install the zoneinfo database in store paths like
+/// `/gnu/store/<hash>-tzdata-2025b/share/zoneinfo/`, which cannot be listed in advance.
+/// For these, everything after the `zoneinfo/` component is used as the time zone name.
+pub(crate) fn strip_zoneinfo_prefix(prefixes: &[String], mut s: String) -> Option<String> {
+    for prefix in prefixes {
+        if s.starts_with(prefix.as_str()) {
+            // Trim to the correct length without allocating.
+            s.replace_range(..prefix.len(), "");
+            return Some(s);
+        }
+    }
+    if s.starts_with("/nix/store/") || s.starts_with("/gnu/store/") {
+        if let Some(index) = s.find("/zoneinfo/") {
+            s.replace_range(..index + "/zoneinfo/".len(), "");
+            return Some(s);
+        }
+    }
+    None
+}
+
+/// The maximum number of symlinks that are followed, like `MAXSYMLINKS` in Linux.
+const MAX_HOPS: usize = 40;
+
+/// Follow the symlinks starting at the absolute `path` inside the file system at `root`.
+///
+/// Returns `path`, followed by the targets of all hops. Each target is made absolute and
+/// normalized lexically, and absolute targets are interpreted relative to `root`. The last
+/// element is not a symlink, or does not exist.
+pub(crate) fn symlink_chain(
+    fs: &dyn FileSystem,
+    root: &Path,
+    path: &Path,
+) -> Result<Vec<PathBuf>, GetTimezoneError> {
+    let mut chain = vec![path.to_owned()];
+    loop {
+        let current = &chain[chain.len() - 1];
+        let target = match fs.read_link(&in_root(root, current)) {
+            Ok(target) => target,
+            Err(_) => return Ok(chain),
+        };
+        let next = normalize(current.parent().unwrap_or(current), &target);
+        if chain.contains(&next) {
+            chain.push(next);
+            return Err(chain_error(&in_root(root, path), "symlink loop", &chain));
+        } else if chain.len() > MAX_HOPS {
+            return Err(chain_error(
+                &in_root(root, path),
+                "too many levels of symlinks",
+                &chain,
+            ));
+        }
+        chain.push(next);
+    }
+}
+
+/// Resolve `target` relative to the directory `base` without accessing the file system.
+fn normalize(base: &Path, target: &Path) -> PathBuf {
+    let mut path = PathBuf::from("/");
+    if target.is_relative() {
+        path.push(base);
+    }
+    for component in target.components() {
+        match component {
+            Component::Prefix(_) | Component::RootDir | Component::CurDir => {}
+            // The parent of the root directory is the root directory.
+            Component::ParentDir => {
+                path.pop();
+            }
+            Component::Normal(name) => path.push(name),
+        }
+    }
+    path
+}
+
+/// An error about the chain of symlinks starting at `first`, the path of `chain[0]` in the root.
+fn chain_error(first: &Path, reason: &str, chain: &[PathBuf]) -> GetTimezoneError {
+    let chain = chain
+        .iter()
+        .map(|path| path.display().to_string())
+        .collect::<Vec<_>>()
+        .join(" -> ");
+    let err = io::Error::new(io::ErrorKind::Other, format!("{}: {}", reason, chain));
+    GetTimezoneError::io(first)(err)
+}
+
+/// Read the time zone identifier from a chain of symlinks into the zoneinfo database.
+///
+/// `path` is absolute inside the file system at `root`. The first hop that points into one of the
+/// [`zoneinfo_prefixes()`] determines the time zone, e.g. for `/etc/localtime -> /etc/alternatives/localtime
+/// -> /usr/share/zoneinfo/Asia/Tokyo`.
+pub(crate) fn read_zoneinfo_link(
+    fs: &dyn FileSystem,
+    root: &Path,
+    path: &Path,
+) -> Result<Detection, GetTimezoneError> {
+    let chain = symlink_chain(fs, root, path)?;
+    if chain.len() < 2 {
+        // Not a symlink, let `read_link()` tell why.
+        let path = in_root(root, path);
+        fs.read_link(&path).map_err(GetTimezoneError::io(&path))?;
+    }
+    let prefixes = zoneinfo_prefixes(root);
+    for hop in &chain[1..] {
+        if let Some(hop) = hop.to_str() {
+            if let Some(name) = strip_zoneinfo_prefix(&prefixes, hop.to_owned()) {
+                return Ok(Detection::new(name, Source::Symlink).with_path(in_root(root, path)));
+            }
+        }
+    }
+    Err(chain_error(
+        &in_root(root, path),
+        "symlink does not point into the zoneinfo database",
+        &chain,
+    ))
+}
+
+/// Read the symlink `/etc/localtime`.
+///
+/// Absolute targets are resolved inside `root`, never in the file system of the host.
+pub(crate) fn etc_localtime(
+    fs: &dyn FileSystem,
+    root: &Path,
+) -> Result<Detection, GetTimezoneError> {
+    // Per <https://www.man7.org/linux/man-pages/man5/localtime.5.html>:
+    // “ The /etc/localtime file configures the system-wide timezone of the local system that is
+    //   used by applications for presentation to the user. It should be an absolute or relative
+    //   symbolic link pointing to /usr/share/zoneinfo/, followed by a timezone identifier such as
+    //   "Europe/Berlin" or "Etc/UTC". The resulting link should lead to the corresponding binary
+    //   tzfile(5) timezone data for the configured timezone. ”
+    read_zoneinfo_link(fs, root, Path::new("/etc/localtime"))
+}
+
+pub(crate) fn etc_timezone(
+    fs: &dyn FileSystem,
+    root: &Path,
+) -> Result<Detection, GetTimezoneError> {
+    // see https://stackoverflow.com/a/12523283
+    let path = in_root(root, "/etc/timezone");
+    let mut contents = fs
+        .read_to_string(&path)
+        .map_err(GetTimezoneError::io(&path))?;
+    // Trim to the correct length without allocating.
+    contents.truncate(contents.trim_end().len());
+    Ok(Detection::new(contents, Source::File).with_path(path))
+}
+
+pub(crate) fn var_db_zoneinfo(
+    fs: &dyn FileSystem,
+    root: &Path,
+) -> Result<Detection, GetTimezoneError> {
+    // see https://gitlab.gnome.org/GNOME/evolution-data-server/-/issues/19
+    let path = in_root(root, "/var/db/zoneinfo");
+    let mut contents = fs
+        .read_to_string(&path)
+        .map_err(GetTimezoneError::io(&path))?;
+    // Trim to the correct length without allocating.
+    contents.truncate(contents.trim_end().len());
+    Ok(Detection::new(contents, Source::File).with_path(path))
+}
+
+pub(crate) fn etc_default_init(
+    fs: &dyn FileSystem,
+    root: &Path,
+) -> Result<Detection, GetTimezoneError> {
+    // https://illumos.org/man/5/TIMEZONE
+    // https://docs.oracle.com/cd/E23824_01/html/821-1473/uc-timezone-4.html
+
+    let path = in_root(root, "/etc/default/init");
+    let contents = fs
+        .read_to_string(&path)
+        .map_err(GetTimezoneError::io(&path))?;
+    match contents.lines().find_map(|line| line.strip_prefix("TZ=")) {
+        Some(name) => Ok(Detection::new(name.trim_end().to_owned(), Source::File).with_path(path)),
+        None => Err(GetTimezoneError::NotConfigured {
+            context: Context::Path(path),
+        }),
+    }
+}
+
+/// Read the time zone from the `TZ` environment variable, the way the C library does.
+///
+/// An unset `TZ`, or `TZ=:`, selects the system default, so an error is returned to let the
+/// caller continue with the next source. POSIX rule strings like `CET-1CEST,M3.5.0,M10.5.0/3`
+/// are not IANA identifiers, but they are what the C library uses, so they are returned as the
+/// name. [`first_of()`](crate::detector::first_of) then replaces the rule with the preferred IANA
+/// time zone with the same rules and reports it in `Detection::posix_tz`, or keeps the rule as
+/// the name if no time zone matches it.
+pub(crate) fn tz_env(fs: &dyn FileSystem) -> Result<Detection, GetTimezoneError> {
+    tz_value(fs, env::var("TZ").ok().as_deref())
+}
+
+/// Interpret the value `tz` of the `TZ` environment variable, see [`tz_env()`].
+fn tz_value(fs: &dyn FileSystem, tz: Option<&str>) -> Result<Detection, GetTimezoneError> {
+    // see https://www.gnu.org/software/libc/manual/html_node/TZ-Variable.html
+    let not_configured = || GetTimezoneError::NotConfigured {
+        context: Context::EnvVar("TZ"),
+    };
+    let tz = tz.ok_or_else(not_configured)?;
+    let name = match parse_tz(tz) {
+        TzVar::Default => return Err(not_configured()),
+        TzVar::Utc => "Etc/UTC".to_owned(),
+        TzVar::Path(path) => {
+            let root = Path::new("/");
+            match strip_zoneinfo_prefix(&zoneinfo_prefixes(root), path.to_owned()) {
+                Some(name) => name,
+                None => read_zoneinfo_link(fs, root, Path::new(path))?.name,
+            }
+        }
+        TzVar::Name(name) => name.to_owned(),
+        TzVar::Rule(rule) => rule.to_owned(),
+    };
+    Ok(Detection::new(name, Source::EnvVar))
+}
+
+/// The interpretation of a `TZ` environment variable.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum TzVar<'a> {
+    /// Use the system default, i.e. `/etc/localtime`.
+    Default,
+    /// An empty `TZ` means UTC.
+    Utc,
+    /// An absolute path to a TZif file.
+    Path(&'a str),
+    /// A file relative to the zoneinfo database, i.e. an IANA time zone identifier.
+    Name(&'a str),
+    /// A POSIX rule string.
+    Rule(&'a str),
+}
+
+fn parse_tz(tz: &str) -> TzVar<'_> {
+    if tz.is_empty() {
+        return TzVar::Utc;
+    }
+    // A leading colon only tells that the rest of the string is implementation defined.
+    let tz = tz.strip_prefix(':').unwrap_or(tz);
+    if tz.is_empty() {
+        TzVar::Default
+    } else if tz.starts_with('/') {
+        TzVar::Path(tz)
+    } else if tz.contains([',', '<', '>']) {
+        TzVar::Rule(tz)
+    } else if tz.contains('/') || !tz.contains(|c: char| c.is_ascii_digit()) {
+        TzVar::Name(tz)
+    } else {
+        // Names like "EST5EDT" are both a file in the zoneinfo database and a POSIX rule
+        // string with the same meaning, so there is no need to tell them apart.
+        TzVar::Rule(tz)
+    }
+}
+
+pub(crate) mod openwrt {
+    use std::iter;
+    use std::path::Path;
+
+    use crate::filesystem::FileSystem;
+    use crate::{Context, Detection, GetTimezoneError, Source};
+
+    pub(crate) fn etc_config_system(
+        fs: &dyn FileSystem,
+        root: &Path,
+    ) -> Result<Detection, GetTimezoneError> {
+        let path = super::in_root(root, "/etc/config/system");
+        let contents = fs
+            .read_to_string(&path)
+            .map_err(GetTimezoneError::io(&path))?;
+        let mut in_system_section = false;
+
+        // prefer option "zonename" (IANA time zone) over option "timezone" (POSIX time zone)
+        let mut timezone = None;
+        for (idx, line) in contents.lines().enumerate() {
+            let mut iter = IterWords(line);
+            let mut next = || {
+                iter.next().transpose().map_err(|BrokenQuote| {
+                    GetTimezoneError::parse(
+                        &path,
+                        format!("unterminated quote in line {}", idx + 1),
+                    )
+                })
+            };
+
+            if let Some(keyword) = next()? {
+                if keyword == "config" {
+                    in_system_section = next()? == Some("system") && next()?.is_none();
+                } else if in_system_section && keyword == "option" {
+                    if let Some(key) = next()? {
+                        if key == "zonename" {
+                            if let (Some(zonename), None) = (next()?, next()?) {
+                                return Ok(Detection::new(zonename.to_owned(), Source::UciConfig)
+                                    .with_path(path));
+                            }
+                        } else if key == "timezone" {
+                            if let (Some(value), None) = (next()?, next()?) {
+                                timezone = Some(value.to_owned());
+                            }
+                        }
+                    }
+                }
+            }
+        }
+
+        match timezone {
+            Some(timezone) => Ok(Detection::new(timezone, Source::UciConfig).with_path(path)),
+            None => Err(GetTimezoneError::NotConfigured {
+                context: Context::Path(path),
+            }),
+        }
+    }
+
+    #[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+    struct BrokenQuote;
+
+    /// Iterated over all words in a OpenWRT config line.
+    struct IterWords<'a>(&'a str);
+
+    impl<'a> Iterator for IterWords<'a> {
+        type Item = Result<&'a str, BrokenQuote>;
+
+        fn next(&mut self) -> Option<Self::Item> {
+            match read_word(self.0) {
+                Ok(Some((item, tail))) => {
+                    self.0 = tail;
+                    Some(Ok(item))
+                }
+                Ok(None) => {
+                    self.0 = "";
+                    None
+                }
+                Err(err) => {
+                    self.0 = "";
+                    Some(Err(err))
+                }
+            }
+        }
+    }
+
+    impl iter::FusedIterator for IterWords<'_> {}
+
+    /// Read the next word in a OpenWRT config line. Strip any surrounding quotation marks.
+    ///
+    /// Returns
+    ///
+    ///  * a tuple `Some((word, remaining_line))` if found,
+    ///  * `None` if the line is exhausted, or
+    ///  * `Err(BrokenQuote)` if the line could not be parsed.
+    #[allow(clippy::manual_strip)] // needs to be compatible to 1.36
+    fn read_word(s: &str) -> Result<Option<(&str, &str)>, BrokenQuote> {
+        let s = s.trim_start();
+        if s.is_empty() || s.starts_with('#') {
+            Ok(None)
+        } else if s.starts_with('\'') {
+            let mut iter = s[1..].splitn(2, '\'');
+            match (iter.next(), iter.next()) {
+                (Some(item), Some(tail)) => Ok(Some((item, tail))),
+                _ => Err(BrokenQuote),
+            }
+        } else if s.starts_with('"') {
+            let mut iter = s[1..].splitn(2, '"');
+            match (iter.next(), iter.next()) {
+                (Some(item), Some(tail)) => Ok(Some((item, tail))),
+                _ => Err(BrokenQuote),
+            }
+        } else {
+            let mut iter = s.splitn(2, |c: char| c.is_whitespace());
+            match (iter.next(), iter.next()) {
+                (Some(item), Some(tail)) => Ok(Some((item, tail))),
+                _ => Ok(Some((s, ""))),
+            }
+        }
+    }
+
+    #[cfg(test)]
+    #[test]
+    fn test_read_word() {
+        assert_eq!(
+            read_word("       option timezone 'CST-8'\n").unwrap(),
+            Some(("option", "timezone 'CST-8'\n")),
+        );
+        assert_eq!(
+            read_word("timezone 'CST-8'\n").unwrap(),
+            Some(("timezone", "'CST-8'\n")),
+        );
+        assert_eq!(read_word("'CST-8'\n").unwrap(), Some(("CST-8", "\n")));
+        assert_eq!(read_word("\n").unwrap(), None);
+
+        assert_eq!(
+            read_word(r#""time 'Zone'""#).unwrap(),
+            Some(("time 'Zone'", "")),
+        );
+
+        assert_eq!(read_word("'CST-8").unwrap_err(), BrokenQuote);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use super::{in_root, normalize, parse_tz, strip_zoneinfo_prefix, zoneinfo_prefixes, TzVar};
+    use crate::filesystem::StdFs;
+    use crate::{Context, GetTimezoneError, Source};
+
+    fn not_configured() -> GetTimezoneError {
+        GetTimezoneError::NotConfigured {
+            context: Context::EnvVar("TZ"),
+        }
+    }
+
+    #[test]
+    fn test_in_root() {
+        assert_eq!(
+            in_root(Path::new("/mnt/image"), "/etc/localtime"),
+            Path::new("/mnt/image/etc/localtime"),
+        );
+        assert_eq!(
+            in_root(Path::new("/"), "/etc/localtime"),
+            Path::new("/etc/localtime"),
+        );
+    }
+
+    #[test]
+    fn test_parse_tz() {
+        assert_eq!(parse_tz(""), TzVar::Utc);
+        assert_eq!(parse_tz(":"), TzVar::Default);
+        assert_eq!(parse_tz("Europe/Berlin"), TzVar::Name("Europe/Berlin"));
+        assert_eq!(parse_tz(":Europe/Berlin"), TzVar::Name("Europe/Berlin"));
+        assert_eq!(parse_tz("Japan"), TzVar::Name("Japan"));
+        assert_eq!(parse_tz("Etc/GMT+5"), TzVar::Name("Etc/GMT+5"));
+        assert_eq!(
+            parse_tz(":/usr/share/zoneinfo/Asia/Tokyo"),
+            TzVar::Path("/usr/share/zoneinfo/Asia/Tokyo"),
+        );
+        assert_eq!(parse_tz(":/etc/localtime"), TzVar::Path("/etc/localtime"));
+        assert_eq!(parse_tz("EST5EDT"), TzVar::Rule("EST5EDT"));
+        assert_eq!(
+            parse_tz("CET-1CEST,M3.5.0,M10.5.0/3"),
+            TzVar::Rule("CET-1CEST,M3.5.0,M10.5.0/3"),
+        );
+        assert_eq!(parse_tz("<+03>-3"), TzVar::Rule("<+03>-3"));
+    }
+
+    #[test]
+    fn test_strip_zoneinfo_prefix() {
+        let prefixes = zoneinfo_prefixes(Path::new("/mnt/image"));
+        let strip = |s: &str| strip_zoneinfo_prefix(&prefixes, s.to_owned());
+        assert_eq!(
+            strip("/usr/share/zoneinfo/Europe/Berlin").unwrap(),
+            "Europe/Berlin"
+        );
+        assert_eq!(strip("/etc/zoneinfo/Etc/UTC").unwrap(), "Etc/UTC");
+        assert_eq!(
+            strip("/usr/share/lib/zoneinfo/Asia/Tokyo").unwrap(),
+            "Asia/Tokyo"
+        );
+        assert_eq!(
+            strip("/gnu/store/0123456789abcdef-tzdata-2025b/share/zoneinfo/America/Sao_Paulo")
+                .unwrap(),
+            "America/Sao_Paulo",
+        );
+        assert_eq!(
+            strip("/nix/store/0123456789abcdef-tzdata-2025b/share/zoneinfo/Etc/UTC").unwrap(),
+            "Etc/UTC",
+        );
+        assert_eq!(strip("/opt/zoneinfo/Etc/UTC"), None);
+        assert_eq!(strip("../usr/share/zoneinfo/Etc/UTC"), None);
+    }
+
+    #[test]
+    fn test_register_zoneinfo_prefix() {
+        let root = Path::new("/");
+        let name = "/opt/iana-time-zone-test/zoneinfo/Europe/Berlin".to_owned();
+        assert_eq!(
+            strip_zoneinfo_prefix(&zoneinfo_prefixes(root), name.clone()),
+            None
+        );
+        super::register_zoneinfo_prefix("/opt/iana-time-zone-test/zoneinfo");
+        assert_eq!(
+            strip_zoneinfo_prefix(&zoneinfo_prefixes(root), name).unwrap(),
+            "Europe/Berlin",
+        );
+    }
+
+    #[test]
+    fn test_normalize() {
+        let etc = Path::new("/etc");
+        assert_eq!(
+            normalize(etc, Path::new("../usr/share/zoneinfo/Etc/UTC")),
+            Path::new("/usr/share/zoneinfo/Etc/UTC"),
+        );
+        assert_eq!(
+            normalize(etc, Path::new("../../../usr/./share/zoneinfo/Etc/UTC")),
+            Path::new("/usr/share/zoneinfo/Etc/UTC"),
+        );
+        assert_eq!(
+            normalize(etc, Path::new("/var/lib/../../usr/share/zoneinfo/UTC")),
+            Path::new("/usr/share/zoneinfo/UTC"),
+        );
+        assert_eq!(
+            normalize(etc, Path::new("alternatives/localtime")),
+            Path::new("/etc/alternatives/localtime"),
+        );
+    }
+
+    #[test]
+    fn test_symlink_chain() {
+        use std::fs;
+        use std::os::unix::fs::symlink;
+
+        let root =
+            std::env::temp_dir().join(format!("iana-time-zone-chain-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+        fs::create_dir_all(root.join("etc/alternatives")).unwrap();
+        fs::create_dir_all(root.join("opt/tz")).unwrap();
+
+        symlink("/etc/alternatives/localtime", root.join("etc/localtime")).unwrap();
+        symlink(
+            "../../../usr/share/zoneinfo/Asia/Tokyo",
+            root.join("etc/alternatives/localtime"),
+        )
+        .unwrap();
+        let detection = super::etc_localtime(&StdFs, &root).unwrap();
+        assert_eq!(detection.name, "Asia/Tokyo");
+        assert_eq!(detection.path.unwrap(), root.join("etc/localtime"));
+
+        fs::remove_file(root.join("etc/alternatives/localtime")).unwrap();
+        symlink("/opt/tz/Tokyo", root.join("etc/alternatives/localtime")).unwrap();
+        let err = super::etc_localtime(&StdFs, &root).unwrap_err().to_string();
+        assert!(
+            err.ends_with("/etc/localtime -> /etc/alternatives/localtime -> /opt/tz/Tokyo"),
+            "{}",
+            err,
+        );
+
+        fs::remove_file(root.join("etc/alternatives/localtime")).unwrap();
+        symlink("../localtime", root.join("etc/alternatives/localtime")).unwrap();
+        let err = super::etc_localtime(&StdFs, &root).unwrap_err().to_string();
+        assert!(err.contains(": symlink loop: "), "{}", err);
+
+        fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn test_detect_in_root() {
+        use std::fs;
+        use std::os::unix::fs::symlink;
+
+        let root = std::env::temp_dir().join(format!("iana-time-zone-root-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+        fs::create_dir_all(root.join("etc")).unwrap();
+
+        // Without configuration, all strategies fail.
+        super::detect_in_root(&root).unwrap_err();
+
+        fs::write(root.join("etc/timezone"), "Europe/Paris\n").unwrap();
+        let detection = super::detect_in_root(&root).unwrap();
+        assert_eq!(detection.name, "Europe/Paris");
+        assert_eq!(detection.source, Source::File);
+        assert_eq!(detection.path.unwrap(), root.join("etc/timezone"));
+        assert_eq!(detection.errors.len(), 1);
+
+        // A name that is neither embedded nor installed is rejected.
+        fs::write(root.join("etc/timezone"), "Mars/Olympus_Mons\n").unwrap();
+        let err = super::detect_in_root(&root).unwrap_err();
+        assert!(
+            matches!(err, GetTimezoneError::InvalidTimezone(name) if name == "Mars/Olympus_Mons")
+        );
+
+        // The installed database may be newer than the embedded list.
+        fs::create_dir_all(root.join("usr/share/zoneinfo/Mars")).unwrap();
+        fs::write(root.join("usr/share/zoneinfo/Mars/Olympus_Mons"), "TZif2").unwrap();
+        let detection = super::detect_in_root(&root).unwrap();
+        assert_eq!(detection.name, "Mars/Olympus_Mons");
+        assert_eq!(
+            super::tzif_path(&StdFs, &root, &detection),
+            Some(root.join("usr/share/zoneinfo/Mars/Olympus_Mons"))
+        );
+        fs::write(root.join("etc/timezone"), "Europe/Paris\n").unwrap();
+
+        // The absolute target does not exist on the host, and is not followed.
+        symlink("/usr/share/zoneinfo/Asia/Tokyo", root.join("etc/localtime")).unwrap();
+        let detection = super::detect_in_root(&root).unwrap();
+        assert_eq!(detection.name, "Asia/Tokyo");
+        assert_eq!(detection.source, Source::Symlink);
+        assert!(detection.errors.is_empty());
+        assert_eq!(
+            super::tzif_path(&StdFs, &root, &detection),
+            Some(root.join("etc/localtime"))
+        );
+
+        fs::remove_dir_all(&root).unwrap();
+    }
+}
+
+/// The sources of all platforms, run against the layouts of their file systems in
+/// `tests/fixtures/layouts/`.
+#[cfg(test)]
+mod layouts {
+    use std::path::Path;
+
+    use super::{FREEBSD, ILLUMOS, LINUX, NETBSD};
+    use crate::detector::{first_of, Strategy};
+    use crate::filesystem::{FileSystem, MemFs};
+    use crate::{BuiltinSource, Detection, GetTimezoneError, Source};
+
+    /// Try `sources` on `fs`, except `TZ`, which is read from the environment of the test.
+    fn detect(fs: &MemFs, sources: &[BuiltinSource]) -> Result<Detection, GetTimezoneError> {
+        let sources = sources
+            .iter()
+            .filter(|&&source| source != BuiltinSource::TZ_ENV);
+        first_of(fs, Path::new("/"), sources)
+    }
+
+    /// The source `TZ` with a fixed value, so the environment of the test is not changed.
+    struct Tz(&'static str);
+
+    impl Strategy for Tz {
+        fn kind(&self) -> Source {
+            Source::EnvVar
+        }
+
+        fn run(&self, fs: &dyn FileSystem, _: &Path) -> Result<Detection, GetTimezoneError> {
+            super::tz_value(fs, Some(self.0))
+        }
+    }
+
+    #[test]
+    fn test_tz_env() {
+        let fs = MemFs::layout("debian");
+        let tz = |value| first_of(&fs, Path::new("/"), [Tz(value)].iter());
+
+        let detection = tz(":Europe/Berlin").unwrap();
+        assert_eq!(detection.name, "Europe/Berlin");
+        assert_eq!(detection.posix_tz, None);
+
+        // A rule is resolved to a representative zone, and reported.
+        let detection = tz("CET-1CEST,M3.5.0,M10.5.0/3").unwrap();
+        assert_eq!(detection.name, "Europe/Brussels");
+        assert_eq!(detection.source, Source::EnvVar);
+        assert_eq!(
+            detection.posix_tz.as_deref(),
+            Some("CET-1CEST,M3.5.0,M10.5.0/3")
+        );
+
+        // A rule that matches no zone is kept, like the C library does.
+        let detection = tz("EST5EDT,M4.1.0,M10.5.0").unwrap();
+        assert_eq!(detection.name, "EST5EDT,M4.1.0,M10.5.0");
+        assert_eq!(
+            detection.posix_tz.as_deref(),
+            Some("EST5EDT,M4.1.0,M10.5.0")
+        );
+
+        assert!(matches!(
+            tz("Mars/Olympus_Mons"),
+            Err(GetTimezoneError::InvalidTimezone(_)),
+        ));
+    }
+
+    #[test]
+    fn test_debian() {
+        let mut fs = MemFs::layout("debian");
+        let detection = detect(&fs, LINUX).unwrap();
+        assert_eq!(detection.name, "Europe/Berlin");
+        assert_eq!(detection.source, Source::Symlink);
+        assert_eq!(detection.path.unwrap(), Path::new("/etc/localtime"));
+        assert!(detection.errors.is_empty());
+
+        fs.remove(Path::new("/etc/localtime"));
+        let detection = detect(&fs, LINUX).unwrap();
+        assert_eq!(detection.name, "Europe/Berlin");
+        assert_eq!(detection.source, Source::File);
+        assert_eq!(detection.path.unwrap(), Path::new("/etc/timezone"));
+        assert!(matches!(
+            detection.errors[..],
+            [(Source::Symlink, GetTimezoneError::IoError { .. })],
+        ));
+    }
+
+    #[test]
+    fn test_alpine() {
+        let mut fs = MemFs::layout("alpine");
+        let detection = detect(&fs, LINUX).unwrap();
+        assert_eq!(detection.name, "Australia/Sydney");
+        assert_eq!(detection.source, Source::Symlink);
+
+        // Without tzdata, there is no time zone.
+        fs.remove(Path::new("/etc/localtime"));
+        fs.remove(Path::new("/etc/zoneinfo"));
+        let err = detect(&fs, LINUX).unwrap_err();
+        assert!(matches!(err, GetTimezoneError::IoError { .. }));
+    }
+
+    #[test]
+    fn test_nixos() {
+        let fs = MemFs::layout("nixos");
+        let detection = detect(&fs, LINUX).unwrap();
+        assert_eq!(detection.name, "Asia/Jerusalem");
+        assert_eq!(detection.source, Source::Symlink);
+        assert_eq!(detection.path.unwrap(), Path::new("/etc/localtime"));
+    }
+
+    #[test]
+    fn test_openwrt() {
+        let mut fs = MemFs::layout("openwrt");
+        let detection = detect(&fs, LINUX).unwrap();
+        assert_eq!(detection.name, "America/New_York");
+        assert_eq!(detection.source, Source::UciConfig);
+        assert_eq!(detection.posix_tz, None);
+
+        // Before OpenWrt 21.02, only the POSIX TZ rule is configured.
+        fs.write(
+            Path::new("/etc/config/system"),
+            "config system\n\toption timezone 'EST5EDT,M3.2.0,M11.1.0'\n",
+        );
+        let detection = detect(&fs, LINUX).unwrap();
+        assert_eq!(detection.name, "America/New_York");
+        assert_eq!(detection.posix_tz.unwrap(), "EST5EDT,M3.2.0,M11.1.0");
+    }
+
+    #[test]
+    fn test_freebsd() {
+        let mut fs = MemFs::layout("freebsd");
+        let detection = detect(&fs, FREEBSD).unwrap();
+        assert_eq!(detection.name, "Europe/Berlin");
+        assert_eq!(detection.source, Source::File);
+        assert_eq!(detection.path.unwrap(), Path::new("/var/db/zoneinfo"));
+
+        // The copy is identified by its contents.
+        fs.remove(Path::new("/var/db/zoneinfo"));
+        let detection = detect(&fs, FREEBSD).unwrap();
+        assert_eq!(detection.name, "Europe/Berlin");
+        assert_eq!(detection.source, Source::FileCopy);
+        assert_eq!(detection.path.unwrap(), Path::new("/etc/localtime"));
+    }
+
+    #[test]
+    fn test_openbsd() {
+        let fs = MemFs::layout("openbsd");
+        let detection = detect(&fs, NETBSD).unwrap();
+        assert_eq!(detection.name, "Australia/Sydney");
+        assert_eq!(detection.source, Source::Symlink);
+    }
+
+    #[test]
+    fn test_solaris() {
+        let mut fs = MemFs::layout("solaris");
+        let detection = detect(&fs, ILLUMOS).unwrap();
+        assert_eq!(detection.name, "Asia/Jerusalem");
+        assert_eq!(detection.source, Source::File);
+        assert_eq!(detection.path.unwrap(), Path::new("/etc/default/init"));
+
+        // The installed database may be newer than the embedded list.
+        fs.write(Path::new("/etc/default/init"), "TZ=Mars/Olympus_Mons\n");
+        detect(&fs, ILLUMOS).unwrap_err();
+        fs.write(
+            Path::new("/usr/share/lib/zoneinfo/Mars/Olympus_Mons"),
+            "TZif2",
+        );
+        assert_eq!(detect(&fs, ILLUMOS).unwrap().name, "Mars/Olympus_Mons");
+    }
+}
@@ -0,0 +1,224 @@
+//! Notifications about changes of the time zone, using inotify on Linux.
+
+use std::os::raw::c_int;
+use std::os::unix::io::AsRawFd;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::thread::{self, JoinHandle};
+use std::time::Instant;
+
+use crate::inotify::{Fd, Inotify, TimezoneChange, DEBOUNCE};
+use crate::{Detection, GetTimezoneError};
+
+/// A running [`watch()`]. Dropping it stops watching.
+#[derive(Debug)]
+pub struct Watcher {
+    stop: Arc<Fd>,
+    thread: Option<JoinHandle<()>>,
+}
+
+/// Call `callback` whenever the time zone of the system changes, e.g. by
+/// `timedatectl set-timezone`.
+///
+/// The files that the time zone is read from are watched with inotify, i.e. `/etc/localtime`,
+/// including the symlinks it points to, `/etc/timezone` and `/etc/config/system`, so no polling
+/// is involved. A burst of events, e.g. by an update that writes a temporary file and renames
+/// it, results in a single detection. `callback` is only called if the detected time zone
+/// differs from the previous one, and it runs in a background thread.
+///
+/// Since the `TZ` environment variable of the process cannot be changed from outside, a time
+/// zone that is set in `TZ` never changes.
+///
+/// The feature `watch` must be enabled, and only Linux is supported.
+///
+/// ```rust,no_run
+/// use std::sync::mpsc;
+///
+/// let (sender, receiver) = mpsc::channel();
+/// let _watcher = iana_time_zone::watch(move |change| {
+///     let _ = sender.send(change);
+/// })?;
+/// for change in receiver {
+///     println!("The time zone changed from {:?} to {}", change.old, change.new);
+/// }
+/// # Ok::<(), iana_time_zone::GetTimezoneError>(())
+/// ```
+pub fn watch<F>(callback: F) -> Result<Watcher, GetTimezoneError>
+where
+    F: FnMut(TimezoneChange) + Send + 'static,
+{
+    Watcher::spawn(PathBuf::from("/"), |_| crate::detect(), callback)
+}
+
+/// Call `callback` whenever the time zone configured in an alternate root file system changes.
+///
+/// See [`watch()`] and [`get_timezone_in_root()`](crate::get_timezone_in_root).
+pub fn watch_in_root<F>(root: &Path, callback: F) -> Result<Watcher, GetTimezoneError>
+where
+    F: FnMut(TimezoneChange) + Send + 'static,
+{
+    Watcher::spawn(root.to_owned(), crate::detect_in_root, callback)
+}
+
+impl Watcher {
+    fn spawn<F>(
+        root: PathBuf,
+        detect: fn(&Path) -> Result<Detection, GetTimezoneError>,
+        callback: F,
+    ) -> Result<Self, GetTimezoneError>
+    where
+        F: FnMut(TimezoneChange) + Send + 'static,
+    {
+        let inotify = Inotify::new(root, detect)?;
+        // SAFETY: The function has no preconditions, and the result is checked.
+        let stop = Fd::new(
+            unsafe { libc::eventfd(0, libc::EFD_CLOEXEC | libc::EFD_NONBLOCK) },
+            "eventfd()",
+        )?;
+        let stop = Arc::new(stop);
+
+        let state = State {
+            inotify,
+            stop: Arc::clone(&stop),
+        };
+        let thread = thread::Builder::new()
+            .name("iana-time-zone-watch".to_owned())
+            .spawn(move || state.run(callback))
+            .map_err(|_| GetTimezoneError::os("std::thread::spawn()", None))?;
+        Ok(Self {
+            stop,
+            thread: Some(thread),
+        })
+    }
+}
+
+impl Drop for Watcher {
+    fn drop(&mut self) {
+        let one = 1_u64.to_ne_bytes();
+        // SAFETY: The buffer is valid for its length. An eventfd accepts writes of 8 bytes.
+        unsafe { libc::write(self.stop.0, one.as_ptr().cast(), one.len()) };
+        if let Some(thread) = self.thread.take() {
+            // The watcher may be dropped by its own callback.
+            if thread.thread().id() != thread::current().id() {
+                let _ = thread.join();
+            }
+        }
+    }
+}
+
+/// The state of the background thread.
+struct State {
+    inotify: Inotify,
+    stop: Arc<Fd>,
+}
+
+enum Wake {
+    Stop,
+    Events,
+    Timeout,
+}
+
+impl State {
+    fn run<F: FnMut(TimezoneChange)>(mut self, mut callback: F) {
+        let mut deadline: Option<Instant> = None;
+        loop {
+            let timeout = match deadline {
+                Some(deadline) => {
+                    let left = deadline.saturating_duration_since(Instant::now());
+                    // Round up, so the deadline has passed when `poll()` returns.
+                    left.as_millis() as c_int + 1
+                }
+                None => -1,
+            };
+            match self.poll(timeout) {
+                Some(Wake::Stop) | None => return,
+                Some(Wake::Events) => {
+                    if self.inotify.read_events() {
+                        deadline = Some(Instant::now() + DEBOUNCE);
+                    }
+                }
+                Some(Wake::Timeout) => {
+                    if deadline.map_or(false, |deadline| Instant::now() >= deadline) {
+                        deadline = None;
+                        if let Some(change) = self.inotify.detect_change() {
+                            callback(change);
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    /// Wait until an event arrives, the watcher is stopped, or `timeout` milliseconds passed.
+    fn poll(&self, timeout: c_int) -> Option<Wake> {
+        let mut fds = [self.stop.0, self.inotify.as_raw_fd()].map(|fd| libc::pollfd {
+            fd,
+            events: libc::POLLIN,
+            revents: 0,
+        });
+        // SAFETY: The buffer is valid for its length.
+        let ret = unsafe { libc::poll(fds.as_mut_ptr(), fds.len() as libc::nfds_t, timeout) };
+        if ret < 0 {
+            // A signal interrupted the call, so just try again.
+            let interrupted =
+                std::io::Error::last_os_error().kind() == std::io::ErrorKind::Interrupted;
+            return if interrupted {
+                Some(Wake::Events)
+            } else {
+                None
+            };
+        }
+        Some(if fds[0].revents != 0 {
+            Wake::Stop
+        } else if fds[1].revents != 0 {
+            Wake::Events
+        } else {
+            Wake::Timeout
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+    use std::sync::mpsc;
+    use std::time::Duration;
+
+    use super::watch_in_root;
+    use crate::TimezoneChange;
+
+    #[test]
+    fn test_watch_in_root() {
+        let root =
+            std::env::temp_dir().join(format!("iana-time-zone-watch-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+        fs::create_dir_all(root.join("etc")).unwrap();
+        fs::write(root.join("etc/timezone"), "Europe/Berlin\n").unwrap();
+
+        let (sender, receiver) = mpsc::channel();
+        let watcher = watch_in_root(&root, move |change| sender.send(change).unwrap()).unwrap();
+
+        // Replace the file atomically, like `timedatectl set-timezone` does.
+        fs::write(root.join("etc/timezone.tmp"), "Europe/Paris\n").unwrap();
+        fs::rename(root.join("etc/timezone.tmp"), root.join("etc/timezone")).unwrap();
+        let change = receiver.recv_timeout(Duration::from_secs(10)).unwrap();
+        assert_eq!(
+            change,
+            TimezoneChange {
+                old: Some("Europe/Berlin".to_owned()),
+                new: "Europe/Paris".to_owned(),
+            }
+        );
+
+        // Writing the same time zone again is not a change.
+        fs::write(root.join("etc/timezone"), "Europe/Paris\n").unwrap();
+        fs::write(root.join("etc/timezone"), "Asia/Tokyo\n").unwrap();
+        let change = receiver.recv_timeout(Duration::from_secs(10)).unwrap();
+        assert_eq!(change.old.as_deref(), Some("Europe/Paris"));
+        assert_eq!(change.new, "Asia/Tokyo");
+
+        drop(watcher);
+        assert!(receiver.recv().is_err());
+        fs::remove_dir_all(&root).unwrap();
+    }
+}
@@ -0,0 +1,328 @@
+//! Identify a time zone by the contents of its file in the zoneinfo database.
+//!
+//! Docker images, Slackware, FreeBSD and many embedded systems copy the TZif file of the
+//! configured time zone to `/etc/localtime` instead of creating a symlink. In this case, the
+//! time zone can only be identified by searching the zoneinfo database for a file with the same
+//! contents.
+
+use std::collections::hash_map::DefaultHasher;
+use std::collections::{HashMap, HashSet};
+use std::hash::{Hash, Hasher};
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex, PoisonError};
+use std::time::SystemTime;
+
+use crate::filesystem::FileSystem;
+use crate::lazy::Lazy;
+use crate::unix::{in_root, symlink_chain, zoneinfo_prefixes};
+use crate::{Detection, GetTimezoneError, Source};
+
+/// Identify the file `/etc/localtime` by comparing it to the files in the zoneinfo database.
+pub(crate) fn etc_localtime_copy(
+    fs: &dyn FileSystem,
+    root: &Path,
+) -> Result<Detection, GetTimezoneError> {
+    let path = Path::new("/etc/localtime");
+    // `/etc/localtime` may also be a symlink to a copy outside of the zoneinfo database.
+    let chain = symlink_chain(fs, root, path)?;
+    let file = in_root(root, &chain[chain.len() - 1]);
+    let contents = fs.read(&file).map_err(GetTimezoneError::io(&file))?;
+    let dir = match zoneinfo_dirs(root).find(|dir| fs.is_dir(dir)) {
+        Some(dir) => dir,
+        None => {
+            let dir = in_root(root, "/usr/share/zoneinfo");
+            let err = io::Error::new(io::ErrorKind::NotFound, "no zoneinfo database found");
+            return Err(GetTimezoneError::io(&dir)(err));
+        }
+    };
+    let name = match ZoneinfoIndex::cached(fs, &dir, &contents)?.find(fs, &dir, &contents) {
+        Ok(name) => name,
+        // tzdata may rewrite the files in place without changing the stamp of the database.
+        Err(Stale) => ZoneinfoIndex::rebuild(fs, &dir, &contents)?
+            .find(fs, &dir, &contents)
+            .unwrap_or(None),
+    };
+    let name = name.ok_or_else(|| {
+        GetTimezoneError::parse(
+            &file,
+            "no file of the zoneinfo database has the same contents",
+        )
+    })?;
+    Ok(Detection::new(name, Source::FileCopy).with_path(in_root(root, path)))
+}
+
+/// Read the POSIX TZ rule string of the current rules from the footer of a TZif file.
+///
+/// The footer only exists since version 2 of the format, and may be empty if the time zone has
+/// no rules.
+pub(crate) fn read_footer(fs: &dyn FileSystem, path: &Path) -> Option<String> {
+    let contents = fs.read(path).ok()?;
+    if !contents.starts_with(b"TZif") || contents.get(4).map_or(true, |&v| v < b'2') {
+        return None;
+    }
+    // The file ends with the footer enclosed in newlines, and the footer has no newlines.
+    let contents = contents.strip_suffix(b"\n")?;
+    let start = contents.iter().rposition(|&b| b == b'\n')? + 1;
+    let footer = std::str::from_utf8(&contents[start..]).ok()?;
+    Some(footer.to_owned()).filter(|footer| !footer.is_empty())
+}
+
+/// The locations of the zoneinfo database inside `root`.
+fn zoneinfo_dirs(root: &Path) -> impl Iterator<Item = PathBuf> + '_ {
+    zoneinfo_prefixes(root)
+        .into_iter()
+        .map(move |prefix| in_root(root, prefix))
+}
+
+/// An index of all files in a zoneinfo database by the hash of their contents.
+#[derive(Debug)]
+struct ZoneinfoIndex {
+    /// Changes when the database is updated.
+    stamp: Stamp,
+    /// The names with the same hash are ordered by preference.
+    by_hash: HashMap<u64, Vec<String>>,
+}
+
+type Stamp = (Option<SystemTime>, Option<SystemTime>);
+
+/// The index does not match the files of the database anymore.
+struct Stale;
+
+impl ZoneinfoIndex {
+    /// Return the index of `dir`, and build it only if it is not cached, or if it is outdated.
+    fn cached(
+        fs: &dyn FileSystem,
+        dir: &Path,
+        contents: &[u8],
+    ) -> Result<Arc<Self>, GetTimezoneError> {
+        let stamp = stamp(fs, dir);
+        let mut cache = INDEX_CACHE
+            .get()
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner);
+        if let Some((_, index)) = cache.iter().find(|(path, _)| path == dir) {
+            if index.stamp == stamp {
+                return Ok(Arc::clone(index));
+            }
+        }
+        let index = Self::build(fs, dir, stamp, contents)?;
+        Ok(Self::store(&mut cache, dir, index))
+    }
+
+    /// Build the index of `dir` again, even if the cached one has the current stamp.
+    fn rebuild(
+        fs: &dyn FileSystem,
+        dir: &Path,
+        contents: &[u8],
+    ) -> Result<Arc<Self>, GetTimezoneError> {
+        let index = Self::build(fs, dir, stamp(fs, dir), contents)?;
+        let mut cache = INDEX_CACHE
+            .get()
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner);
+        Ok(Self::store(&mut cache, dir, index))
+    }
+
+    fn store(cache: &mut IndexCache, dir: &Path, index: Self) -> Arc<Self> {
+        let index = Arc::new(index);
+        cache.retain(|(path, _)| path != dir);
+        cache.push((dir.to_owned(), Arc::clone(&index)));
+        index
+    }
+
+    /// Build the index of `dir`. The hash of `contents` is always indexed, even without any
+    /// names, so that a file that is not in the database is not looked for again.
+    fn build(
+        fs: &dyn FileSystem,
+        dir: &Path,
+        stamp: Stamp,
+        contents: &[u8],
+    ) -> Result<Self, GetTimezoneError> {
+        let mut files = Vec::new();
+        walk(fs, dir, "", &mut files).map_err(GetTimezoneError::io(dir))?;
+
+        let canonical = read_zone1970_tab(fs, dir);
+        let links = read_tzdata_zi_links(fs, dir);
+        let rank = |name: &str| {
+            if name.starts_with("right/") {
+                3
+            } else if canonical.contains(name) {
+                0
+            } else if !links.contains(name) {
+                1
+            } else {
+                2
+            }
+        };
+
+        let mut by_hash: HashMap<u64, Vec<String>> = HashMap::new();
+        by_hash.entry(hash(contents)).or_default();
+        for (name, hash) in files {
+            by_hash.entry(hash).or_default().push(name);
+        }
+        for names in by_hash.values_mut() {
+            names.sort_by(|a, b| (rank(a), a).cmp(&(rank(b), b)));
+        }
+        Ok(Self { stamp, by_hash })
+    }
+
+    /// Find the preferred name of the file in `dir` that has the same `contents`.
+    ///
+    /// Fails if the hash of `contents` was never indexed, or if the preferred file has changed.
+    fn find(
+        &self,
+        fs: &dyn FileSystem,
+        dir: &Path,
+        contents: &[u8],
+    ) -> Result<Option<String>, Stale> {
+        let name = match self.by_hash.get(&hash(contents)).ok_or(Stale)?.first() {
+            Some(name) => name,
+            None => return Ok(None),
+        };
+        // Don't trust the hash alone, the file may have been changed, too.
+        match fs.read(&dir.join(name)) {
+            Ok(file) if file == contents => Ok(Some(name.clone())),
+            _ => Err(Stale),
+        }
+    }
+}
+
+/// The cached indices by the path of their zoneinfo database.
+type IndexCache = Vec<(PathBuf, Arc<ZoneinfoIndex>)>;
+
+static INDEX_CACHE: Lazy<Mutex<IndexCache>> = Lazy::new(|| Mutex::new(Vec::new()));
+
+/// The modification times of the database and its index, which change when tzdata is updated.
+fn stamp(fs: &dyn FileSystem, dir: &Path) -> Stamp {
+    let mtime = |path: &Path| fs.modified(path).ok();
+    (mtime(dir), mtime(&dir.join("zone1970.tab")))
+}
+
+fn hash(contents: &[u8]) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    contents.hash(&mut hasher);
+    hasher.finish()
+}
+
+/// Collect the names and the hashes of the contents of all TZif files below `dir`.
+fn walk(
+    fs: &dyn FileSystem,
+    dir: &Path,
+    prefix: &str,
+    files: &mut Vec<(String, u64)>,
+) -> io::Result<()> {
+    for (file_name, is_dir) in fs.read_dir(dir)? {
+        let name = format!("{}{}", prefix, file_name);
+        if is_dir {
+            // "posix/" duplicates the whole database. "right/" contains the variants with leap
+            // seconds, which differ from the other files.
+            if name != "posix" {
+                walk(fs, &dir.join(&file_name), &format!("{}/", name), files)?;
+            }
+        } else if !matches!(name.as_str(), "localtime" | "posixrules") {
+            // Links in the database may be symlinks, which are followed. Symlinks to directories
+            // are skipped, because they might form a loop.
+            if let Ok(contents) = fs.read(&dir.join(&file_name)) {
+                if contents.starts_with(b"TZif") {
+                    files.push((name, hash(&contents)));
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Read the canonical time zones from `zone1970.tab`.
+fn read_zone1970_tab(fs: &dyn FileSystem, dir: &Path) -> HashSet<String> {
+    let contents = fs
+        .read_to_string(&dir.join("zone1970.tab"))
+        .unwrap_or_default();
+    contents
+        .lines()
+        .filter(|line| !line.starts_with('#'))
+        .filter_map(|line| line.split('\t').nth(2))
+        .map(|name| name.to_owned())
+        .collect()
+}
+
+/// Read the names of all links from `tzdata.zi`.
+fn read_tzdata_zi_links(fs: &dyn FileSystem, dir: &Path) -> HashSet<String> {
+    let contents = fs
+        .read_to_string(&dir.join("tzdata.zi"))
+        .unwrap_or_default();
+    contents
+        .lines()
+        .filter_map(|line| {
+            let mut words = line.split_whitespace();
+            match (words.next(), words.next(), words.next()) {
+                (Some("L"), Some(_), Some(alias)) => Some(alias.to_owned()),
+                _ => None,
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use super::etc_localtime_copy;
+    use crate::filesystem::StdFs;
+    use crate::Source;
+
+    #[test]
+    fn test_etc_localtime_copy() {
+        let root = std::env::temp_dir().join(format!("iana-time-zone-copy-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+        let zoneinfo = root.join("usr/share/zoneinfo");
+        fs::create_dir_all(zoneinfo.join("Europe")).unwrap();
+        fs::create_dir_all(zoneinfo.join("Arctic")).unwrap();
+        fs::create_dir_all(zoneinfo.join("posix/Europe")).unwrap();
+        fs::create_dir_all(root.join("etc")).unwrap();
+
+        for name in [
+            "Europe/Berlin",
+            "Europe/Oslo",
+            "Arctic/Longyearbyen",
+            "posix/Europe/Berlin",
+        ] {
+            fs::write(zoneinfo.join(name), b"TZif2 Berlin").unwrap();
+        }
+        fs::write(zoneinfo.join("Europe/Paris"), b"TZif2 Paris").unwrap();
+        fs::write(
+            zoneinfo.join("zone1970.tab"),
+            "DE,NO\t+5230+01322\tEurope/Berlin\n",
+        )
+        .unwrap();
+        fs::write(zoneinfo.join("tzdata.zi"), "L Europe/Berlin Europe/Oslo\n").unwrap();
+
+        // The canonical name is preferred over the identical links.
+        fs::write(root.join("etc/localtime"), b"TZif2 Berlin").unwrap();
+        let detection = etc_localtime_copy(&StdFs, &root).unwrap();
+        assert_eq!(detection.name, "Europe/Berlin");
+        assert_eq!(detection.source, Source::FileCopy);
+
+        // The index is built again if tzdata was updated in place.
+        fs::write(zoneinfo.join("Europe/Berlin"), b"TZif2 Berlin (updated)").unwrap();
+        fs::write(root.join("etc/localtime"), b"TZif2 Berlin (updated)").unwrap();
+        let detection = etc_localtime_copy(&StdFs, &root).unwrap();
+        assert_eq!(detection.name, "Europe/Berlin");
+
+        // The outdated copy matches the files that were not updated.
+        fs::write(root.join("etc/localtime"), b"TZif2 Berlin").unwrap();
+        let detection = etc_localtime_copy(&StdFs, &root).unwrap();
+        assert_eq!(detection.name, "Arctic/Longyearbyen");
+
+        fs::write(root.join("etc/localtime"), b"TZif2 Paris").unwrap();
+        assert_eq!(
+            etc_localtime_copy(&StdFs, &root).unwrap().name,
+            "Europe/Paris"
+        );
+
+        fs::write(root.join("etc/localtime"), b"TZif2 Tokyo").unwrap();
+        etc_localtime_copy(&StdFs, &root).unwrap_err();
+
+        fs::remove_dir_all(&root).unwrap();
+    }
+}
@@ -0,0 +1,79 @@
+//! Generate `src/windows_zones.rs` from the file `common/supplemental/windowsZones.xml` of CLDR.
+//!
+//! Usage: `cargo run --manifest-path tzdata_gen/Cargo.toml --bin windows_zones --
+//! <path/to/windowsZones.xml> <CLDR version>`
+
+use std::fmt::Write as _;
+use std::process::Command;
+use std::{env, fs};
+
+const OUT: &str = "src/windows_zones.rs";
+
+fn main() {
+    let mut args = env::args().skip(1);
+    let (path, version) = match (args.next(), args.next()) {
+        (Some(path), Some(version)) => (path, version),
+        _ => panic!("usage: windows_zones <path/to/windowsZones.xml> <CLDR version>"),
+    };
+    let contents = fs::read_to_string(path).unwrap();
+
+    let mut zones: Vec<(String, String, Vec<String>)> = contents
+        .lines()
+        .filter(|line| line.trim_start().starts_with("<mapZone "))
+        .map(|line| {
+            let ids = attribute(line, "type");
+            (
+                attribute(line, "other"),
+                attribute(line, "territory"),
+                ids.split_whitespace().map(|id| id.to_owned()).collect(),
+            )
+        })
+        .collect();
+    zones.sort();
+
+    let mut out = String::new();
+    writeln!(
+        out,
+        "// Generated by `tzdata_gen` from CLDR {} windowsZones.xml",
+        version
+    )
+    .unwrap();
+    writeln!(out).unwrap();
+    writeln!(
+        out,
+        "/// The mapping as `(windows_id, territory, iana_ids)`, sorted by Windows ID and territory."
+    )
+    .unwrap();
+    writeln!(
+        out,
+        "pub(crate) static WINDOWS_ZONES: &[(&str, &str, &[&str])] = &["
+    )
+    .unwrap();
+    for (other, territory, ids) in &zones {
+        writeln!(out, "    ({:?}, {:?}, &{:?}),", other, territory, ids).unwrap();
+    }
+    writeln!(out, "];").unwrap();
+
+    fs::write(OUT, out).unwrap();
+    let status = Command::new("rustfmt")
+        .args(["--edition", "2021", OUT])
+        .status()
+        .unwrap();
+    assert!(status.success(), "rustfmt failed");
+}
+
+/// Read the value of the XML attribute `name` in `line`.
+fn attribute(line: &str, name: &str) -> String {
+    let start = line
+        .find(&format!(" {}=\"", name))
+        .unwrap_or_else(|| panic!("no attribute {:?} in {:?}", name, line))
+        + name.len()
+        + 3;
+    let len = line[start..].find('"').unwrap();
+    line[start..start + len]
+        .replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&apos;", "'")
+        .replace("&amp;", "&")
+}
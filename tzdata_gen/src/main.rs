@@ -0,0 +1,148 @@
+//! Generate `src/tzdata.rs` from the source files of the IANA time zone database.
+//!
+//! Usage: `cargo run --manifest-path tzdata_gen/Cargo.toml --bin tzdata_gen --
+//! <path/to/tzdata> <path/to/zoneinfo>`, where the first directory contains the unpacked
+//! `tzdata*.tar.gz`, e.g. the files `backward` and `NEWS`, and the second one contains the same
+//! version compiled by `zic`, e.g. `/usr/share/zoneinfo`.
+
+use std::fmt::Write as _;
+use std::path::Path;
+use std::process::Command;
+use std::{env, fs};
+
+const OUT: &str = "src/tzdata.rs";
+
+/// The source files of the zones and links that `zic` installs by default. The zone `Factory`
+/// in the file `factory` is not a real time zone.
+const SOURCES: &[&str] = &[
+    "africa",
+    "antarctica",
+    "asia",
+    "australasia",
+    "europe",
+    "northamerica",
+    "southamerica",
+    "etcetera",
+    "backward",
+];
+
+fn main() {
+    let mut args = env::args_os().skip(1);
+    let (dir, zoneinfo) = match (args.next(), args.next()) {
+        (Some(dir), Some(zoneinfo)) => (dir, zoneinfo),
+        _ => panic!("usage: tzdata_gen <path/to/tzdata> <path/to/zoneinfo>"),
+    };
+    let dir = Path::new(&dir);
+    let zoneinfo = Path::new(&zoneinfo);
+
+    let version = read_version(dir);
+    let mut links = read_links(&dir.join("backward"));
+    links.sort();
+    links.dedup();
+
+    let mut names = Vec::new();
+    let mut zones = Vec::new();
+    for file in SOURCES {
+        let (file_zones, file_links) = read_names(&dir.join(file));
+        names.extend(file_zones.iter().cloned());
+        names.extend(file_links);
+        zones.extend(file_zones);
+    }
+    names.sort();
+    names.dedup();
+    zones.sort();
+    zones.dedup();
+
+    let mut out = String::new();
+    writeln!(out, "// Generated by `tzdata_gen` from tzdata {}", version).unwrap();
+    writeln!(out).unwrap();
+    writeln!(
+        out,
+        "/// The links of the file `backward` as `(alias, target)`, sorted by alias."
+    )
+    .unwrap();
+    writeln!(out, "pub(crate) static LINKS: &[(&str, &str)] = &[").unwrap();
+    for (alias, target) in &links {
+        writeln!(out, "    ({:?}, {:?}),", alias, target).unwrap();
+    }
+    writeln!(out, "];").unwrap();
+    writeln!(out).unwrap();
+    writeln!(out, "/// The names of all zones and links, sorted.").unwrap();
+    writeln!(out, "pub(crate) static NAMES: &[&str] = &[").unwrap();
+    for name in &names {
+        writeln!(out, "    {:?},", name).unwrap();
+    }
+    writeln!(out, "];").unwrap();
+    writeln!(out).unwrap();
+    writeln!(
+        out,
+        "/// The POSIX TZ rule strings of the current rules of all zones, sorted by zone."
+    )
+    .unwrap();
+    writeln!(out, "pub(crate) static POSIX_TZ: &[(&str, &str)] = &[").unwrap();
+    for zone in &zones {
+        let footer = read_footer(&zoneinfo.join(zone));
+        writeln!(out, "    ({:?}, {:?}),", zone, footer).unwrap();
+    }
+    writeln!(out, "];").unwrap();
+
+    fs::write(OUT, out).unwrap();
+    let status = Command::new("rustfmt")
+        .args(["--edition", "2021", OUT])
+        .status()
+        .unwrap();
+    assert!(status.success(), "rustfmt failed");
+}
+
+/// Read the version from the first release line of `NEWS`, e.g. "Release 2025b - ...".
+fn read_version(dir: &Path) -> String {
+    let news = fs::read_to_string(dir.join("NEWS")).unwrap();
+    news.lines()
+        .find_map(|line| line.strip_prefix("Release "))
+        .and_then(|line| line.split_whitespace().next())
+        .expect("no release in NEWS")
+        .to_owned()
+}
+
+/// Read the names of the lines `Zone NAME ...` and `Link TARGET LINK-NAME` of a source file.
+fn read_names(path: &Path) -> (Vec<String>, Vec<String>) {
+    let contents = fs::read_to_string(path).unwrap();
+    let mut zones = Vec::new();
+    let mut links = Vec::new();
+    for line in contents.lines() {
+        let mut words = line.split_whitespace();
+        match (words.next(), words.next(), words.next()) {
+            (Some("Zone"), Some(name), _) => zones.push(name.to_owned()),
+            (Some("Link"), Some(_), Some(name)) => links.push(name.to_owned()),
+            _ => {}
+        }
+    }
+    (zones, links)
+}
+
+/// Read the POSIX TZ rule string from the footer of a TZif file of version 2 or later.
+fn read_footer(path: &Path) -> String {
+    let contents = fs::read(path).unwrap();
+    assert!(contents.starts_with(b"TZif") && contents[4] >= b'2');
+    let contents = contents.strip_suffix(b"\n").expect("no footer");
+    let start = contents.iter().rposition(|&b| b == b'\n').unwrap() + 1;
+    String::from_utf8(contents[start..].to_vec()).unwrap()
+}
+
+/// Read the lines `Link TARGET LINK-NAME` of a source file as `(alias, target)`.
+fn read_links(path: &Path) -> Vec<(String, String)> {
+    let contents = fs::read_to_string(path).unwrap();
+    contents
+        .lines()
+        .filter_map(|line| {
+            let line = line.split('#').next().unwrap_or_default();
+            let mut words = line.split_whitespace();
+            match (words.next(), words.next(), words.next()) {
+                (Some("Link"), Some(target), Some(alias)) => {
+                    Some((alias.to_owned(), target.to_owned()))
+                }
+                _ => None,
+            }
+        })
+        .collect()
+}